@@ -0,0 +1,224 @@
+use std::{fs, path::Path, time::Duration};
+
+use serde::{Deserialize, Serialize};
+use shared::{
+    error::ParseError,
+    solution::{Timer, Timing},
+};
+
+/// Summary of one step over every iteration, in nanoseconds so it survives a round trip
+/// through JSON.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub mean: u64,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Stats {
+        let mut nanos: Vec<u64> = samples.iter().map(|x| x.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+        let Some(&min) = nanos.first() else {
+            return Stats::default();
+        };
+        let middle = nanos.len() / 2;
+        let median = match nanos.len() % 2 {
+            0 => (nanos[middle - 1] + nanos[middle]) / 2,
+            _ => nanos[middle],
+        };
+        let mean = nanos.iter().sum::<u64>() / nanos.len() as u64;
+        Stats { min, median, mean }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: usize,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+impl DayBench {
+    pub fn steps(&self) -> [(&'static str, Stats); 3] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part_one),
+            ("part 2", self.part_two),
+        ]
+    }
+}
+
+/// Times `input` `iterations` times, each iteration parses the input again. Solutions with a
+/// global cache, like day 11, only pay for it in the first iteration, which the mean shows.
+pub fn measure(
+    day: usize,
+    timer: Timer,
+    input: &str,
+    iterations: usize,
+) -> Result<DayBench, ParseError> {
+    let timings = (0..iterations.max(1))
+        .map(|_| timer(input))
+        .collect::<Result<Vec<Timing>, ParseError>>()?;
+    let stats =
+        |step: fn(&Timing) -> Duration| Stats::new(&timings.iter().map(step).collect::<Vec<_>>());
+    Ok(DayBench {
+        day,
+        iterations: timings.len(),
+        parse: stats(|x| x.parse),
+        part_one: stats(|x| x.part_one),
+        part_two: stats(|x| x.part_two),
+    })
+}
+
+fn format_nanos(nanos: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos))
+}
+
+pub fn table(results: &[DayBench]) -> String {
+    let mut lines = vec![format!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "day", "step", "min", "median", "mean"
+    )];
+    for result in results {
+        for (step, stats) in result.steps() {
+            lines.push(format!(
+                "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+                result.day,
+                step,
+                format_nanos(stats.min),
+                format_nanos(stats.median),
+                format_nanos(stats.mean)
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+pub fn to_json(results: &[DayBench]) -> String {
+    serde_json::to_string_pretty(results).expect("benchmarks should serialize")
+}
+
+pub fn save(path: &Path, results: &[DayBench]) -> Result<(), String> {
+    fs::write(path, to_json(results) + "\n")
+        .map_err(|error| format!("cannot write {}: {}", path.display(), error))
+}
+
+pub fn load(path: &Path) -> Result<Vec<DayBench>, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+    serde_json::from_str(&text).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub day: usize,
+    pub step: &'static str,
+    pub before: u64,
+    pub after: u64,
+}
+
+impl Regression {
+    pub fn describe(&self) -> String {
+        format!(
+            "day {} {}: median {} -> {} (+{}%)",
+            self.day,
+            self.step,
+            format_nanos(self.before),
+            format_nanos(self.after),
+            (self.after - self.before) * 100 / self.before.max(1)
+        )
+    }
+}
+
+/// Steps whose median grew by more than `threshold` percent since `baseline`. Days that are
+/// not in the baseline are not compared.
+pub fn regressions(results: &[DayBench], baseline: &[DayBench], threshold: u32) -> Vec<Regression> {
+    let mut found = vec![];
+    for result in results {
+        let Some(old) = baseline.iter().find(|x| x.day == result.day) else {
+            continue;
+        };
+        for ((step, now), (_, before)) in result.steps().into_iter().zip(old.steps()) {
+            let limit = before.median as u128 * (100 + threshold as u128) / 100;
+            if now.median as u128 > limit {
+                found.push(Regression {
+                    day: result.day,
+                    step,
+                    before: before.median,
+                    after: now.median,
+                });
+            }
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+fn bench(day: usize, parse: u64, part_one: u64, part_two: u64) -> DayBench {
+    let stats = |median| Stats {
+        min: median,
+        median,
+        mean: median,
+    };
+    DayBench {
+        day,
+        iterations: 1,
+        parse: stats(parse),
+        part_one: stats(part_one),
+        part_two: stats(part_two),
+    }
+}
+
+#[test]
+fn test_stats() {
+    let samples = [5, 1, 3, 10].map(Duration::from_nanos);
+
+    assert_eq!(
+        Stats::new(&samples),
+        Stats {
+            min: 1,
+            median: 4,
+            mean: 4
+        }
+    );
+    assert_eq!(Stats::new(&samples[..3]).median, 3);
+    assert_eq!(Stats::new(&[]), Stats::default());
+}
+
+#[test]
+fn test_measure() {
+    let timer = shared::solution::time::<day_7::Day7> as Timer;
+    let result = measure(7, timer, "190: 10 19\n3267: 81 40 27", 3).unwrap();
+
+    assert_eq!(result.iterations, 3);
+    assert!(result.parse.min <= result.parse.median);
+    assert!(measure(7, timer, "190 10 19", 3).is_err());
+}
+
+#[test]
+fn test_regressions() {
+    let baseline = vec![bench(1, 100, 100, 100), bench(2, 100, 100, 100)];
+    let results = vec![bench(1, 105, 200, 90), bench(3, 1000, 1000, 1000)];
+    let found = regressions(&results, &baseline, 10);
+
+    assert_eq!(
+        found,
+        vec![Regression {
+            day: 1,
+            step: "part 1",
+            before: 100,
+            after: 200
+        }]
+    );
+    assert_eq!(
+        found[0].describe(),
+        "day 1 part 1: median 100.00ns -> 200.00ns (+100%)"
+    );
+    assert_eq!(
+        serde_json::from_str::<Vec<DayBench>>(&to_json(&results)).unwrap(),
+        results
+    );
+}
@@ -0,0 +1,215 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::Deserialize;
+use shared::solution::{Answer, Part, Solver};
+
+/// One entry of `answers.toml`:
+///
+/// ```toml
+/// [[answer]]
+/// day = 7
+/// part = 2
+/// input = "example"
+/// expected = 11387
+/// ```
+///
+/// `input` is found through `shared::input` and defaults to "puzzle", the real input.
+/// `expected` is a number or a string.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct KnownAnswer {
+    pub day: usize,
+    pub part: usize,
+    #[serde(default = "default_input")]
+    pub input: String,
+    pub expected: toml::Value,
+}
+
+fn default_input() -> String {
+    "puzzle".to_string()
+}
+
+impl KnownAnswer {
+    fn expected_text(&self) -> String {
+        match &self.expected {
+            toml::Value::String(text) => text.clone(),
+            other => other.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct AnswersFile {
+    #[serde(default)]
+    answer: Vec<KnownAnswer>,
+}
+
+pub fn parse_answers(text: &str) -> Result<Vec<KnownAnswer>, String> {
+    let file: AnswersFile = toml::from_str(text).map_err(|error| error.to_string())?;
+    for answer in &file.answer {
+        if Part::from_number(answer.part).is_none() {
+            return Err(format!(
+                "day {} has part {}, parts are 1 or 2",
+                answer.day, answer.part
+            ));
+        }
+    }
+    Ok(file.answer)
+}
+
+pub fn load(path: &Path) -> Result<Vec<KnownAnswer>, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+    parse_answers(&text).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Failed {
+        expected: String,
+        actual: String,
+    },
+    /// The solution could not run, like a missing input or a day that is not registered.
+    Error(String),
+}
+
+/// Runs every known answer. Each input is read and parsed once, and only the parts that have
+/// a known answer are solved.
+pub fn check(
+    answers: &[KnownAnswer],
+    solver: impl Fn(usize) -> Option<Solver>,
+    read_input: impl Fn(usize, &str) -> Result<String, String>,
+) -> Vec<(&KnownAnswer, Outcome)> {
+    let mut by_input: BTreeMap<(usize, &str), Vec<&KnownAnswer>> = BTreeMap::new();
+    for answer in answers {
+        by_input
+            .entry((answer.day, answer.input.as_str()))
+            .or_default()
+            .push(answer);
+    }
+
+    let mut results = vec![];
+    for ((day, input), known) in by_input {
+        let part = match known.iter().all(|x| x.part == known[0].part) {
+            true => Part::from_number(known[0].part),
+            false => None,
+        };
+        let solved = solver(day)
+            .ok_or_else(|| format!("day {} has no solution", day))
+            .and_then(|solver| {
+                let text = read_input(day, input)?;
+                solver(&text, part).map_err(|error| format!("invalid input, {}", error))
+            });
+
+        for answer in known {
+            let outcome = match &solved {
+                Ok(answers) => compare(answer, answers),
+                Err(error) => Outcome::Error(error.clone()),
+            };
+            results.push((answer, outcome));
+        }
+    }
+    results
+}
+
+fn compare(known: &KnownAnswer, answers: &[(Part, Answer)]) -> Outcome {
+    let actual = answers
+        .iter()
+        .find(|(part, _)| Part::from_number(known.part) == Some(*part))
+        .map(|(_, answer)| answer.to_string())
+        .unwrap_or_default();
+    let expected = known.expected_text();
+    match expected == actual {
+        true => Outcome::Passed,
+        false => Outcome::Failed { expected, actual },
+    }
+}
+
+/// Lists the answers that did not pass, with a line diff for every mismatch, and a summary.
+pub fn report(results: &[(&KnownAnswer, Outcome)]) -> String {
+    let mut lines = vec![];
+    for (known, outcome) in results {
+        let name = format!("day {} part {} ({})", known.day, known.part, known.input);
+        match outcome {
+            Outcome::Passed => {}
+            Outcome::Failed { expected, actual } => {
+                lines.push(format!("{}: wrong answer", name));
+                lines.extend(expected.lines().map(|x| format!("  - {}", x)));
+                lines.extend(actual.lines().map(|x| format!("  + {}", x)));
+            }
+            Outcome::Error(error) => lines.push(format!("{}: {}", name, error)),
+        }
+    }
+
+    let count = |wanted: fn(&Outcome) -> bool| results.iter().filter(|x| wanted(&x.1)).count();
+    lines.push(format!(
+        "{} passed, {} failed, {} errors",
+        count(|x| *x == Outcome::Passed),
+        count(|x| matches!(x, Outcome::Failed { .. })),
+        count(|x| matches!(x, Outcome::Error(_))),
+    ));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+const ANSWERS: &str = r#"
+[[answer]]
+day = 7
+part = 1
+input = "example"
+expected = 3457
+
+[[answer]]
+day = 7
+part = 2
+input = "example"
+expected = "3456"
+
+[[answer]]
+day = 7
+part = 1
+expected = 1
+
+[[answer]]
+day = 30
+part = 1
+expected = 1
+"#;
+
+#[test]
+fn test_parse_answers() {
+    let answers = parse_answers(ANSWERS).unwrap();
+
+    assert_eq!(answers.len(), 4);
+    assert_eq!(answers[2].input, "puzzle");
+    assert_eq!(answers[1].expected_text(), "3456");
+    assert!(parse_answers("[[answer]]\nday = 1\npart = 3\nexpected = 1").is_err());
+}
+
+#[test]
+fn test_check() {
+    let answers = parse_answers(ANSWERS).unwrap();
+    let solver = |day| (day == 7).then_some(shared::solution::solve::<day_7::Day7> as Solver);
+    let read_input = |_: usize, input: &str| match input {
+        "example" => Ok("190: 10 19\n3267: 81 40 27".to_string()),
+        _ => Err(format!("no {}", input)),
+    };
+
+    let results = check(&answers, solver, read_input);
+    let outcomes: Vec<&Outcome> = results.iter().map(|x| &x.1).collect();
+
+    assert_eq!(
+        outcomes,
+        vec![
+            &Outcome::Passed,
+            &Outcome::Failed {
+                expected: "3456".to_string(),
+                actual: "3457".to_string()
+            },
+            &Outcome::Error("no puzzle".to_string()),
+            &Outcome::Error("day 30 has no solution".to_string()),
+        ]
+    );
+    assert!(report(&results).ends_with("1 passed, 1 failed, 2 errors"));
+    assert!(report(&results).contains("day 7 part 2 (example): wrong answer\n  - 3456\n  + 3457"));
+}
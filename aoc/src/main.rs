@@ -0,0 +1,484 @@
+mod bench;
+mod check;
+mod scaffold;
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use shared::{
+    anim::Recorder,
+    error::ParseError,
+    input::{self, Kind},
+    sim::{self, Simulation},
+    solution::{solve, time, Part, Solver, Timer},
+};
+
+const USAGE: &str = "usage:
+  aoc run [--day <day>] [--part <1|2>] [--input <path>]
+  aoc check [--day <day>] [--answers <path>]
+  aoc bench [--day <day>] [--iterations <n>] [--format <table|json>]
+            [--save <path>] [--baseline <path>] [--threshold <percent>]
+  aoc new --day <day>
+  aoc step --day <6|14|15> [--input <path>] [--record <path> [--every <n>]]";
+
+macro_rules! days {
+    ($($day:literal => $solution:ty),* $(,)?) => {
+        [$(($day, solve::<$solution> as Solver, time::<$solution> as Timer)),*]
+    };
+}
+
+const DAYS: [(usize, Solver, Timer); 15] = days![
+    1 => day_1::Day1,
+    2 => day_2::Day2,
+    3 => day_3::Day3,
+    4 => day_4::Day4,
+    5 => day_5::Day5,
+    6 => day_6::Day6,
+    7 => day_7::Day7,
+    8 => day_8::Day8,
+    9 => day_9::Day9,
+    10 => day_10::Day10,
+    11 => day_11::Day11,
+    12 => day_12::Day12,
+    13 => day_13::Day13,
+    14 => day_14::Day14,
+    15 => day_15::Day15,
+];
+
+fn solver(day: usize) -> Option<Solver> {
+    DAYS.iter().find(|x| x.0 == day).map(|x| x.1)
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+enum Command {
+    #[default]
+    Run,
+    Check,
+    Bench,
+    New,
+    Step,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+enum Format {
+    #[default]
+    Table,
+    Json,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Options {
+    command: Command,
+    day: Option<usize>,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    answers: Option<PathBuf>,
+    iterations: Option<usize>,
+    format: Format,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: Option<u32>,
+    record: Option<PathBuf>,
+    every: Option<usize>,
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let command = match args.next().as_deref() {
+        Some("run") => Command::Run,
+        Some("check") => Command::Check,
+        Some("bench") => Command::Bench,
+        Some("new") => Command::New,
+        Some("step") => Command::Step,
+        _ => return Err(USAGE.to_string()),
+    };
+
+    let mut options = Options {
+        command,
+        ..Options::default()
+    };
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("{} needs a value\n{}", flag, USAGE))?;
+        match flag.as_str() {
+            "--day" => {
+                let day = value
+                    .parse()
+                    .map_err(|_| format!("day should be a number, got {}", value))?;
+                options.day = Some(day);
+            }
+            "--part" => {
+                let part = value
+                    .parse()
+                    .ok()
+                    .and_then(Part::from_number)
+                    .ok_or_else(|| format!("part should be 1 or 2, got {}", value))?;
+                options.part = Some(part);
+            }
+            "--input" if matches!(options.command, Command::Run | Command::Step) => {
+                options.input = Some(PathBuf::from(value))
+            }
+            "--answers" if options.command == Command::Check => {
+                options.answers = Some(PathBuf::from(value))
+            }
+            "--iterations" if options.command == Command::Bench => {
+                let iterations = value
+                    .parse()
+                    .ok()
+                    .filter(|x| *x > 0)
+                    .ok_or_else(|| format!("iterations should be at least 1, got {}", value))?;
+                options.iterations = Some(iterations);
+            }
+            "--format" if options.command == Command::Bench => {
+                options.format = match value.as_str() {
+                    "table" => Format::Table,
+                    "json" => Format::Json,
+                    _ => return Err(format!("format should be table or json, got {}", value)),
+                }
+            }
+            "--save" if options.command == Command::Bench => {
+                options.save = Some(PathBuf::from(value))
+            }
+            "--baseline" if options.command == Command::Bench => {
+                options.baseline = Some(PathBuf::from(value))
+            }
+            "--threshold" if options.command == Command::Bench => {
+                let threshold = value
+                    .parse()
+                    .map_err(|_| format!("threshold should be a percentage, got {}", value))?;
+                options.threshold = Some(threshold);
+            }
+            "--record" if options.command == Command::Step => {
+                options.record = Some(PathBuf::from(value))
+            }
+            "--every" if options.command == Command::Step => {
+                let every = value
+                    .parse()
+                    .ok()
+                    .filter(|x| *x > 0)
+                    .ok_or_else(|| format!("every should be at least 1, got {}", value))?;
+                options.every = Some(every);
+            }
+            _ => return Err(format!("unknown option {}\n{}", flag, USAGE)),
+        }
+    }
+
+    if options.input.is_some() && options.day.is_none() {
+        return Err("--input needs --day".to_string());
+    }
+    if options.every.is_some() && options.record.is_none() {
+        return Err("--every needs --record".to_string());
+    }
+    if matches!(options.command, Command::New | Command::Step) && options.day.is_none() {
+        return Err(format!("new and step need --day\n{}", USAGE));
+    }
+    Ok(options)
+}
+
+fn read_input(day: usize, path: Option<&PathBuf>) -> Result<String, String> {
+    match path {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|error| format!("cannot read {}: {}", path.display(), error)),
+        None => input::read(day, Kind::Real).map_err(|error| error.to_string()),
+    }
+}
+
+fn selected_days(options: &Options) -> Result<Vec<&'static (usize, Solver, Timer)>, String> {
+    let days: Vec<_> = DAYS
+        .iter()
+        .filter(|(day, _, _)| options.day.is_none_or(|x| x == *day))
+        .collect();
+    match days.is_empty() {
+        true => Err(format!(
+            "day {} has no solution",
+            options.day.unwrap_or_default()
+        )),
+        false => Ok(days),
+    }
+}
+
+/// Runs every selected day. A day without valid input is reported and skipped, so one bad
+/// file does not hide the answers of the other days.
+fn run(options: &Options) -> Result<(), String> {
+    let mut failed = 0;
+    for (day, solver, _) in selected_days(options)? {
+        let input = match read_input(*day, options.input.as_ref()) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("day {}: {}", day, error);
+                failed += 1;
+                continue;
+            }
+        };
+        match solver(&input, options.part) {
+            Ok(answers) => {
+                for (part, answer) in answers {
+                    println!("day {} {}: {}", day, part, answer);
+                }
+            }
+            Err(error) => {
+                eprintln!("day {}: invalid input, {}", day, error);
+                failed += 1;
+            }
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} day(s) failed", failed)),
+    }
+}
+
+fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner should be inside the workspace")
+}
+
+/// Compares the answers in `answers.toml` with what the solutions give now.
+fn run_check(options: &Options) -> Result<(), String> {
+    let path = options
+        .answers
+        .clone()
+        .unwrap_or_else(|| workspace_dir().join("answers.toml"));
+    let answers: Vec<check::KnownAnswer> = check::load(&path)?
+        .into_iter()
+        .filter(|x| options.day.is_none_or(|day| day == x.day))
+        .collect();
+
+    let results = check::check(&answers, solver, |day, name| {
+        input::read(day, Kind::from_name(name)).map_err(|error| error.to_string())
+    });
+    println!("{}", check::report(&results));
+
+    match results.iter().all(|x| x.1 == check::Outcome::Passed) {
+        true => Ok(()),
+        false => Err("some answers changed".to_string()),
+    }
+}
+
+/// Times the real input of every selected day and compares the medians with a saved baseline.
+fn run_bench(options: &Options) -> Result<(), String> {
+    let iterations = options.iterations.unwrap_or(10);
+    let mut results = vec![];
+    let mut failed = 0;
+    for (day, _, timer) in selected_days(options)? {
+        let measured = read_input(*day, None).and_then(|input| {
+            bench::measure(*day, *timer, &input, iterations)
+                .map_err(|error| format!("invalid input, {}", error))
+        });
+        match measured {
+            Ok(result) => results.push(result),
+            Err(error) => {
+                eprintln!("day {}: {}", day, error);
+                failed += 1;
+            }
+        }
+    }
+
+    match options.format {
+        Format::Table => println!("{}", bench::table(&results)),
+        Format::Json => println!("{}", bench::to_json(&results)),
+    }
+    if let Some(path) = &options.save {
+        bench::save(path, &results)?;
+    }
+
+    let mut regressed = 0;
+    if let Some(path) = &options.baseline {
+        let baseline = bench::load(path)?;
+        let found = bench::regressions(&results, &baseline, options.threshold.unwrap_or(10));
+        for regression in &found {
+            eprintln!("regression: {}", regression.describe());
+        }
+        regressed = found.len();
+    }
+
+    match (failed, regressed) {
+        (0, 0) => Ok(()),
+        (0, _) => Err(format!("{} step(s) got slower", regressed)),
+        _ => Err(format!("{} day(s) failed", failed)),
+    }
+}
+
+/// Creates the crate of a new day. The runner only learns about it once it is added to `DAYS`
+/// and to the dependencies of `aoc`.
+fn run_new(options: &Options) -> Result<(), String> {
+    let day = options.day.unwrap_or_default();
+    for path in scaffold::new_day(workspace_dir(), day)? {
+        println!("wrote {}", path.display());
+    }
+    println!(
+        "to run it with aoc, add \"day-{0}\" to aoc/Cargo.toml and `{0} => day_{0}::Day{0}` to DAYS",
+        day
+    );
+    Ok(())
+}
+
+/// Steps through the simulation of a day in the terminal, on the real input unless `--input`
+/// is given. With `--record` the frames are written to a file instead. Day 14 always uses the
+/// size of the real map.
+fn run_step(options: &Options) -> Result<(), String> {
+    let day = options.day.unwrap_or_default();
+    let input = read_input(day, options.input.as_ref())?;
+    let invalid = |error: ParseError| format!("invalid input, {}", error);
+    match day {
+        6 => step(
+            day_6::GuardSimulation::new(&input).map_err(invalid)?,
+            options,
+        ),
+        14 => step(
+            day_14::RobotSimulation::new(&input).map_err(invalid)?,
+            options,
+        ),
+        15 => step(
+            day_15::WarehouseSimulation::new(&input).map_err(invalid)?,
+            options,
+        ),
+        _ => Err(format!("day {} has no simulation", day)),
+    }
+}
+
+fn step<S: Simulation>(simulation: S, options: &Options) -> Result<(), String> {
+    match &options.record {
+        Some(path) => record(simulation, path, options.every.unwrap_or(1)),
+        None => interactive(simulation),
+    }
+}
+
+/// Most frames a recording keeps, day 14 would otherwise never stop.
+const MAX_FRAMES: usize = 1000;
+
+/// Records every `every`th step into a GIF when `path` ends in `.gif`, otherwise into a
+/// directory of numbered PPM files.
+fn record<S: Simulation>(mut simulation: S, path: &Path, every: usize) -> Result<(), String> {
+    let mut recorder = Recorder::new().every(every).max_frames(MAX_FRAMES);
+    sim::record(&mut simulation, &mut recorder);
+    let written = match path.extension().is_some_and(|x| x == "gif") {
+        true => write_gif(&recorder, path),
+        false => recorder.write_ppm_sequence(path, "frame").map(|_| ()),
+    };
+    written.map_err(|error| format!("cannot write {}: {}", path.display(), error))?;
+    println!(
+        "wrote {} frame(s) to {}",
+        recorder.frames().len(),
+        path.display()
+    );
+    Ok(())
+}
+
+#[cfg(feature = "gif")]
+fn write_gif(recorder: &Recorder, path: &Path) -> io::Result<()> {
+    recorder.write_gif(path, 10)
+}
+
+#[cfg(not(feature = "gif"))]
+fn write_gif(_: &Recorder, _: &Path) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "GIFs need aoc built with the gif feature",
+    ))
+}
+
+#[cfg(feature = "tui")]
+fn interactive<S: Simulation>(simulation: S) -> Result<(), String> {
+    sim::run(simulation).map_err(|error| format!("terminal error, {}", error))
+}
+
+#[cfg(not(feature = "tui"))]
+fn interactive<S: Simulation>(_: S) -> Result<(), String> {
+    Err(
+        "stepping in the terminal needs aoc built with the tui feature, --record works without it"
+            .to_string(),
+    )
+}
+
+fn main() -> ExitCode {
+    let result = parse_options(env::args().skip(1)).and_then(|options| match options.command {
+        Command::Run => run(&options),
+        Command::Check => run_check(&options),
+        Command::Bench => run_bench(&options),
+        Command::New => run_new(&options),
+        Command::Step => run_step(&options),
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+fn args(line: &str) -> impl Iterator<Item = String> + '_ {
+    line.split(' ').map(String::from)
+}
+
+#[test]
+fn test_parse_options() {
+    assert_eq!(
+        parse_options(args("run --day 7 --part 2 --input example.txt")),
+        Ok(Options {
+            day: Some(7),
+            part: Some(Part::Two),
+            input: Some(PathBuf::from("example.txt")),
+            ..Options::default()
+        })
+    );
+    assert_eq!(
+        parse_options(args("check --answers answers.toml")),
+        Ok(Options {
+            command: Command::Check,
+            answers: Some(PathBuf::from("answers.toml")),
+            ..Options::default()
+        })
+    );
+    assert_eq!(
+        parse_options(args(
+            "bench --iterations 5 --format json --baseline old.json"
+        )),
+        Ok(Options {
+            command: Command::Bench,
+            iterations: Some(5),
+            format: Format::Json,
+            baseline: Some(PathBuf::from("old.json")),
+            ..Options::default()
+        })
+    );
+    assert_eq!(
+        parse_options(args("new --day 16")),
+        Ok(Options {
+            command: Command::New,
+            day: Some(16),
+            ..Options::default()
+        })
+    );
+    assert_eq!(
+        parse_options(args("step --day 14 --record robots.gif --every 5")),
+        Ok(Options {
+            command: Command::Step,
+            day: Some(14),
+            record: Some(PathBuf::from("robots.gif")),
+            every: Some(5),
+            ..Options::default()
+        })
+    );
+    assert_eq!(parse_options(args("run")), Ok(Options::default()));
+
+    assert!(parse_options(args("run --part 3")).is_err());
+    assert!(parse_options(args("run --input example.txt")).is_err());
+    assert!(parse_options(args("run --day")).is_err());
+    assert!(parse_options(args("check --input example.txt")).is_err());
+    assert!(parse_options(args("bench --iterations 0")).is_err());
+    assert!(parse_options(args("bench --format csv")).is_err());
+    assert!(parse_options(args("run --save out.json")).is_err());
+    assert!(parse_options(args("new")).is_err());
+    assert!(parse_options(args("step --input example.txt")).is_err());
+    assert!(parse_options(args("step --day 6 --every 2")).is_err());
+    assert!(parse_options(args("run --record out.gif")).is_err());
+    assert!(parse_options(args("time")).is_err());
+}
@@ -0,0 +1,179 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TOML: &str = r#"[package]
+name = "day-DAY"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+"shared" = { path = "../shared" }
+"#;
+
+const MAIN_RS: &str = "use std::process::ExitCode;
+
+use day_DAY::DayDAY;
+use shared::solution::run;
+
+fn main() -> ExitCode {
+    run::<DayDAY>(DAY)
+}
+";
+
+const LIB_RS: &str = "use shared::{
+    error::ParseError,
+    solution::{Answer, Solution},
+};
+
+#[cfg(test)]
+use shared::{
+    input::{self, Kind},
+    solution::{solve, Part},
+};
+
+pub struct DayDAY;
+
+impl Solution for DayDAY {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_one(_: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part_two(_: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = input::read(DAY, Kind::Example).unwrap();
+
+    assert_eq!(
+        solve::<DayDAY>(&input, None),
+        Ok(vec![
+            (Part::One, Answer::Unsolved),
+            (Part::Two, Answer::Unsolved)
+        ])
+    );
+}
+";
+
+/// Adds `member` to the `members` list of a workspace manifest and sorts the list. Only that
+/// line is rewritten, so the rest of the file keeps its formatting.
+pub fn add_member(manifest: &str, member: &str) -> Result<String, String> {
+    let parsed: toml::Table = manifest
+        .parse()
+        .map_err(|error: toml::de::Error| error.to_string())?;
+    let mut members: Vec<String> = parsed
+        .get("workspace")
+        .and_then(|x| x.get("members"))
+        .and_then(|x| x.as_array())
+        .ok_or("the manifest has no workspace members")?
+        .iter()
+        .filter_map(|x| x.as_str().map(String::from))
+        .collect();
+    if members.iter().any(|x| x == member) {
+        return Err(format!("{} is already a workspace member", member));
+    }
+    members.push(member.to_string());
+    members.sort();
+
+    let start = manifest
+        .find("members = [")
+        .ok_or("members should be written as `members = [...]`")?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("the members list is not closed")?;
+    let list: Vec<String> = members.iter().map(|x| format!("{:?}", x)).collect();
+    Ok(format!(
+        "{}members = [{}]{}",
+        &manifest[..start],
+        list.join(", "),
+        &manifest[end + 1..]
+    ))
+}
+
+/// Creates the `day-N` crate in `workspace` with an empty solution, an example test and
+/// placeholder inputs, and adds it to the workspace. Returns the files that were written.
+pub fn new_day(workspace: &Path, day: usize) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day-{}", day);
+    let crate_dir = workspace.join(&name);
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    let manifest_path = workspace.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .map_err(|error| format!("cannot read {}: {}", manifest_path.display(), error))?;
+    let manifest = add_member(&manifest, &name)?;
+
+    let day_text = day.to_string();
+    let files = [
+        (crate_dir.join("Cargo.toml"), CARGO_TOML),
+        (crate_dir.join("src/main.rs"), MAIN_RS),
+        (crate_dir.join("src/lib.rs"), LIB_RS),
+        (crate_dir.join("src/example.txt"), ""),
+        (workspace.join("inputs").join(&name).join("puzzle.txt"), ""),
+    ];
+    let mut written = vec![];
+    for (path, template) in files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| format!("cannot create {}: {}", parent.display(), error))?;
+        }
+        if template.is_empty() && path.exists() {
+            continue;
+        }
+        fs::write(&path, template.replace("DAY", &day_text))
+            .map_err(|error| format!("cannot write {}: {}", path.display(), error))?;
+        written.push(path);
+    }
+
+    fs::write(&manifest_path, manifest)
+        .map_err(|error| format!("cannot write {}: {}", manifest_path.display(), error))?;
+    written.push(manifest_path);
+    Ok(written)
+}
+
+#[test]
+fn test_add_member() {
+    let manifest =
+        "[workspace]\nmembers = [\"aoc\", \"day-1\", \"day-2\", \"shared\"]\nresolver = \"2\"\n";
+
+    assert_eq!(
+        add_member(manifest, "day-10").unwrap(),
+        "[workspace]\nmembers = [\"aoc\", \"day-1\", \"day-10\", \"day-2\", \"shared\"]\nresolver = \"2\"\n"
+    );
+    assert!(add_member(manifest, "day-2").is_err());
+    assert!(add_member("[package]\nname = \"x\"\n", "day-2").is_err());
+}
+
+#[test]
+fn test_new_day() {
+    let workspace = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    fs::create_dir_all(&workspace).unwrap();
+    fs::write(
+        workspace.join("Cargo.toml"),
+        "[workspace]\nmembers = [\"day-1\"]\n",
+    )
+    .unwrap();
+
+    let written = new_day(&workspace, 16).unwrap();
+    let main = fs::read_to_string(workspace.join("day-16/src/main.rs")).unwrap();
+    let manifest = fs::read_to_string(workspace.join("Cargo.toml")).unwrap();
+    let result = new_day(&workspace, 16);
+    fs::remove_dir_all(&workspace).unwrap();
+
+    assert_eq!(written.len(), 6);
+    assert!(main.contains("run::<Day16>(16)"));
+    assert_eq!(manifest, "[workspace]\nmembers = [\"day-1\", \"day-16\"]\n");
+    assert!(result.is_err());
+}
@@ -0,0 +1,88 @@
+use shared::{
+    error::ParseError,
+    parse::ints_n,
+    solution::{Answer, Solution},
+};
+
+fn split_to_sides(str: String) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+    let lines = str.lines();
+    let mut left = vec![];
+    let mut right = vec![];
+    for (index, line) in lines.enumerate() {
+        let [first, second] = ints_n::<usize, 2>(line)
+            .ok_or_else(|| ParseError::at(index, line, line, "two numbers"))?;
+        left.push(first);
+        right.push(second);
+    }
+    Ok((left, right))
+}
+
+fn pair_and_calc_distance(left: Vec<usize>, right: Vec<usize>) -> Vec<usize> {
+    let mut distances = vec![];
+    for i in 0..left.len() {
+        distances.push(left[i].abs_diff(right[i]))
+    }
+    distances
+}
+
+fn calc_similarity(left: Vec<usize>, right: Vec<usize>) -> usize {
+    let mut score = 0usize;
+    for num in left {
+        score += right.iter().filter(|f| **f == num).sum::<usize>();
+    }
+    score
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<usize>, Vec<usize>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        split_to_sides(input.to_string())
+    }
+
+    fn part_one((left, right): &Self::Input) -> Answer {
+        let mut left = left.clone();
+        let mut right = right.clone();
+        left.sort();
+        right.sort();
+
+        pair_and_calc_distance(left, right)
+            .iter()
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_two((left, right): &Self::Input) -> Answer {
+        calc_similarity(left.clone(), right.clone()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::input::{self, Kind};
+
+    #[test]
+    fn test_split() {
+        let input = input::read(1, Kind::Named("test-input")).unwrap();
+        let (left, right) = split_to_sides(input).unwrap();
+
+        assert_eq!(left, vec![3, 4, 2, 1, 3, 3]);
+        assert_eq!(right, vec![4, 3, 5, 3, 9, 3]);
+
+        let error = split_to_sides("3   4\n5".to_string()).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 1, "5", "two numbers"));
+    }
+
+    #[test]
+    fn test_calc_similarity() {
+        let input = input::read(1, Kind::Named("test-input")).unwrap();
+        let (left, right) = split_to_sides(input).unwrap();
+
+        let similarity = calc_similarity(left, right);
+
+        assert_eq!(similarity, 31);
+    }
+}
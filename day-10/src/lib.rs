@@ -0,0 +1,94 @@
+use shared::{
+    error::ParseError,
+    map,
+    search::bfs,
+    solution::{Answer, Solution},
+};
+
+#[cfg(test)]
+use shared::input::{self, Kind};
+
+pub type Tile = u32;
+
+pub type Map = map::Map<Tile>;
+
+fn find_trailheads(map: &Map, find_all: bool) -> usize {
+    let starting_positions = map.iter().filter(|x| *x.1 == 0);
+
+    let mut trailheads = 0usize;
+    for (position, _) in starting_positions {
+        // Height goes up by one on every step, so every trail is a shortest path
+        let search = bfs(
+            *position,
+            |position| {
+                let height = *map.get(position).unwrap();
+                map.neighbours(*position, false)
+                    .filter(move |x| *x.1 == height + 1)
+                    .map(|x| x.0)
+            },
+            |position| map.get(position) == Some(&9),
+        );
+        trailheads += match find_all {
+            true => search.path_count(),
+            false => search.goals.len(),
+        };
+    }
+    trailheads
+}
+
+fn parse_map(input: &str) -> Result<Map, ParseError> {
+    // Examples mark impassable tiles with '.', which is too high to ever climb onto
+    Map::try_parse(
+        input,
+        |char| match char {
+            '.' => Some(10),
+            _ => char.to_digit(10),
+        },
+        "a height or '.'",
+    )
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part_one(map: &Self::Input) -> Answer {
+        find_trailheads(map, false).into()
+    }
+
+    fn part_two(map: &Self::Input) -> Answer {
+        find_trailheads(map, true).into()
+    }
+}
+
+#[test]
+fn test_example_part_one() {
+    let example = input::read(10, Kind::Example).unwrap();
+    let map = parse_map(&example).unwrap();
+
+    let trailheads = find_trailheads(&map, false);
+    assert_eq!(trailheads, 36);
+}
+
+#[test]
+fn test_example_part_two() {
+    let example = input::read(10, Kind::Example).unwrap();
+    let map = parse_map(&example).unwrap();
+
+    let trailheads = find_trailheads(&map, true);
+    assert_eq!(trailheads, 81);
+}
+
+#[test]
+fn test_example2() {
+    let example = input::read(10, Kind::Named("example2")).unwrap();
+    let map = parse_map(&example).unwrap();
+
+    let trailheads = find_trailheads(&map, false);
+    assert_eq!(trailheads, 4);
+}
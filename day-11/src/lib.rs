@@ -0,0 +1,73 @@
+use cached::proc_macro::cached;
+use shared::{
+    error::{number, ParseError},
+    solution::{Answer, Solution},
+};
+
+fn digit_count(num: usize) -> usize {
+    num.to_string().len()
+}
+
+fn split_number(num: usize) -> Vec<usize> {
+    let string = num.to_string();
+    let split = string.split_at(string.len() / 2);
+    vec![split.0.parse().unwrap(), split.1.parse().unwrap()]
+}
+
+#[cached]
+fn blink_stone(current_blink: usize, to_blink: usize, number: usize) -> usize {
+    let result: Vec<usize> = {
+        if number == 0 {
+            vec![1]
+        } else if digit_count(number).is_multiple_of(2) {
+            split_number(number)
+        } else {
+            vec![number * 2024]
+        }
+    };
+
+    if current_blink < to_blink - 1 {
+        result
+            .iter()
+            .fold(0, |a, x| a + blink_stone(current_blink + 1, to_blink, *x))
+    } else {
+        result.len()
+    }
+}
+
+fn parse_stones(input: &str) -> Result<Vec<usize>, ParseError> {
+    input
+        .split_whitespace()
+        .map(|x| number::<usize>(0, input, x))
+        .collect()
+}
+
+fn blink_stones(numbers: &[usize], blink_amount: usize) -> usize {
+    numbers
+        .iter()
+        .fold(0usize, |a, x| a + blink_stone(0, blink_amount, *x))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_stones(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        blink_stones(input, 25).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        blink_stones(input, 75).into()
+    }
+}
+
+#[test]
+fn test_split_number() {
+    assert_eq!(split_number(99), vec![9, 9]);
+    assert_eq!(split_number(253000), vec![253, 0]);
+}
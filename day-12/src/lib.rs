@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+
+use shared::{
+    components::label,
+    error::ParseError,
+    grid::Grid,
+    region,
+    solution::{Answer, Solution},
+    vector::Vector,
+};
+
+#[cfg(test)]
+use shared::input::{self, Kind};
+
+pub type Garden = Grid<char>;
+
+/// A patch of one plant and what its fence needs.
+#[derive(Debug)]
+struct Region {
+    area: usize,
+    perimeter: usize,
+    sides: usize,
+}
+
+impl Region {
+    fn price(&self, use_sides: bool) -> usize {
+        match use_sides {
+            true => self.area * self.sides,
+            false => self.area * self.perimeter,
+        }
+    }
+}
+
+/// Regions come in reading order of their first plot.
+fn find_regions(garden: &Garden) -> Vec<Region> {
+    let components = label(garden, false, |a, b| a == b);
+
+    components
+        .cells
+        .into_iter()
+        .map(|plots| {
+            let plots: HashSet<Vector> = plots.into_iter().collect();
+            Region {
+                area: region::area(&plots),
+                perimeter: region::perimeter(&plots),
+                sides: region::sides(&plots),
+            }
+        })
+        .collect()
+}
+
+fn parse_garden(input: &str) -> Result<Garden, ParseError> {
+    Garden::try_parse(input, Some, "a plant")
+}
+
+fn total_price(garden: &Garden, use_sides: bool) -> usize {
+    find_regions(garden)
+        .iter()
+        .map(|x| x.price(use_sides))
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Garden;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_garden(input)
+    }
+
+    fn part_one(garden: &Self::Input) -> Answer {
+        total_price(garden, false).into()
+    }
+
+    fn part_two(garden: &Self::Input) -> Answer {
+        total_price(garden, true).into()
+    }
+}
+
+#[test]
+fn test_region_find() {
+    let input = input::read(12, Kind::Example).unwrap();
+    let garden = parse_garden(&input).unwrap();
+    let regions = find_regions(&garden);
+
+    assert_eq!(regions.len(), 5);
+}
+
+#[test]
+fn test_region_find_exact() {
+    let input = input::read(12, Kind::Named("example2")).unwrap();
+    let garden = parse_garden(&input).unwrap();
+    let regions = find_regions(&garden);
+
+    let [a_region, b_region, c_region, d_region, e_region] = &regions[..] else {
+        panic!("expected 5 regions, found {}", regions.len());
+    };
+
+    assert_eq!(a_region.area, 4);
+    assert_eq!(b_region.area, 4);
+    assert_eq!(c_region.area, 4);
+    assert_eq!(d_region.area, 1);
+    assert_eq!(e_region.area, 3);
+
+    assert_eq!(a_region.perimeter, 10);
+    assert_eq!(b_region.perimeter, 8);
+    assert_eq!(c_region.perimeter, 10);
+    assert_eq!(d_region.perimeter, 4);
+    assert_eq!(e_region.perimeter, 8);
+
+    assert_eq!(a_region.sides, 4);
+    assert_eq!(b_region.sides, 4);
+    assert_eq!(c_region.sides, 8);
+    assert_eq!(d_region.sides, 4);
+    assert_eq!(e_region.sides, 4);
+}
+
+#[test]
+fn test_pricing() {
+    let input = input::read(12, Kind::Example).unwrap();
+    let garden = parse_garden(&input).unwrap();
+    let regions = find_regions(&garden);
+    let total_price: usize = regions.iter().map(|x| x.price(false)).sum();
+
+    assert_eq!(total_price, 772);
+
+    let input = input::read(12, Kind::Named("example2")).unwrap();
+    let garden = parse_garden(&input).unwrap();
+    let regions = find_regions(&garden);
+    let total_price: usize = regions.iter().map(|x| x.price(false)).sum();
+    assert_eq!(total_price, 140);
+}
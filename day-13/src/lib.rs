@@ -0,0 +1,222 @@
+use shared::{
+    error::ParseError,
+    linear::{self, Solutions},
+    math,
+    parse::records,
+    solution::{Answer, Solution},
+};
+
+#[cfg(test)]
+use shared::input::{self, Kind};
+
+type Number = isize;
+
+#[derive(Default, Clone, Debug)]
+pub struct Machine {
+    a_x: Number,
+    a_y: Number,
+
+    b_x: Number,
+    b_y: Number,
+
+    p_x: Number,
+    p_y: Number,
+}
+
+impl Machine {
+    fn calculate(&self, prize_offset: Number) -> Option<Number> {
+        let p_x = self.p_x.checked_add(prize_offset)?;
+        let p_y = self.p_y.checked_add(prize_offset)?;
+
+        let presses = linear::solve(
+            &[vec![self.a_x, self.b_x], vec![self.a_y, self.b_y]],
+            &[p_x, p_y],
+        );
+        let tokens = match presses.ok()? {
+            Solutions::Unique(presses) => {
+                let a = presses[0].to_integer().filter(|x| *x >= 0)?;
+                let b = presses[1].to_integer().filter(|x| *x >= 0)?;
+                a.checked_mul(3)?.checked_add(b)?
+            }
+            Solutions::Inconsistent => return None,
+            // Both buttons move along one line, so an axis that either of them moves along
+            // says it all. When neither moves at all the prize is at the start.
+            Solutions::Infinite { .. } => {
+                let (a, b, p) = match (self.a_x, self.b_x) {
+                    (0, 0) => (self.a_y, self.b_y, p_y),
+                    _ => (self.a_x, self.b_x, p_x),
+                };
+                cheapest(a as i128, b as i128, p as i128)?
+            }
+        };
+        Number::try_from(tokens).ok()
+    }
+}
+
+/// Fewest tokens for `a * a_step + b * b_step == target` with `a` and `b` not negative.
+fn cheapest(a_step: i128, b_step: i128, target: i128) -> Option<i128> {
+    let whole = |step: i128| (target % step == 0 && target / step >= 0).then(|| target / step);
+    match (a_step, b_step) {
+        (0, 0) => return (target == 0).then_some(0),
+        (0, _) => return whole(b_step),
+        (_, 0) => return whole(a_step).map(|a| a * 3),
+        _ => {}
+    }
+    let (g, x, y) = math::extended_gcd(a_step, b_step);
+    if target % g != 0 {
+        return None;
+    }
+
+    // Every solution is (a + k * step_a, b + k * step_b), flipped so that A grows with k
+    let (a, b) = (x.checked_mul(target / g)?, y.checked_mul(target / g)?);
+    let (step_a, step_b) = match b_step / g > 0 {
+        true => (b_step / g, -a_step / g),
+        false => (-b_step / g, a_step / g),
+    };
+    let mut lowest = -a.div_euclid(step_a);
+    let highest = match step_b > 0 {
+        true => {
+            lowest = lowest.max(-b.div_euclid(step_b));
+            None
+        }
+        false => Some(b.div_euclid(-step_b)),
+    };
+    if highest.is_some_and(|highest| lowest > highest) {
+        return None;
+    }
+
+    // Each k costs 3 * step_a + step_b tokens more, so the best k is at the cheap end, which
+    // only lacks a bound when the tokens keep growing
+    let k = match 3 * step_a + step_b < 0 {
+        true => highest?,
+        false => lowest,
+    };
+    let a = a.checked_add(k.checked_mul(step_a)?)?;
+    let b = b.checked_add(k.checked_mul(step_b)?)?;
+    a.checked_mul(3)?.checked_add(b)
+}
+
+fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+    records(input, |[button_a, button_b, prize]| {
+        let [a_x, a_y] = button_a.ints_n("Button A: X+<number>, Y+<number>")?;
+        let [b_x, b_y] = button_b.ints_n("Button B: X+<number>, Y+<number>")?;
+        let [p_x, p_y] = prize.ints_n("Prize: X=<number>, Y=<number>")?;
+        Ok(Machine {
+            a_x,
+            a_y,
+            b_x,
+            b_y,
+            p_x,
+            p_y,
+        })
+    })
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(machines: &Self::Input) -> Answer {
+        machines
+            .iter()
+            .filter_map(|x| x.calculate(0))
+            .sum::<Number>()
+            .into()
+    }
+
+    fn part_two(machines: &Self::Input) -> Answer {
+        machines
+            .iter()
+            .filter_map(|x| x.calculate(10000000000000))
+            .sum::<Number>()
+            .into()
+    }
+}
+
+#[test]
+fn test_calc() {
+    let machine = Machine {
+        a_x: 94,
+        a_y: 34,
+        b_x: 22,
+        b_y: 67,
+        p_x: 8400,
+        p_y: 5400,
+    };
+
+    assert_eq!(machine.calculate(0), Some(280));
+
+    let machine = Machine {
+        a_x: 26,
+        a_y: 66,
+        b_x: 67,
+        b_y: 21,
+        p_x: 12748,
+        p_y: 12176,
+    };
+
+    assert_eq!(machine.calculate(0), None);
+}
+
+#[test]
+fn test_parallel_buttons() {
+    let mut machine = Machine {
+        a_x: 2,
+        a_y: 4,
+        b_x: 3,
+        b_y: 6,
+        p_x: 12,
+        p_y: 24,
+    };
+
+    // Four presses of B are cheaper than six of A or three of A and two of B
+    assert_eq!(machine.calculate(0), Some(4));
+
+    machine.p_y = 25;
+    assert_eq!(machine.calculate(0), None);
+
+    machine.b_x = 9;
+    machine.b_y = 18;
+    machine.p_x = 22;
+    machine.p_y = 44;
+    // Two presses of each beat eleven of A
+    assert_eq!(machine.calculate(0), Some(8));
+
+    // Neither button moves along X, so the Y axis decides
+    let machine = Machine {
+        a_x: 0,
+        a_y: 2,
+        b_x: 0,
+        b_y: 3,
+        p_x: 0,
+        p_y: 12,
+    };
+    assert_eq!(machine.calculate(0), Some(4));
+    assert_eq!(machine.calculate(1), None);
+
+    let machine = Machine::default();
+    assert_eq!(machine.calculate(0), Some(0));
+}
+
+#[test]
+fn test_example() {
+    let example = input::read(13, Kind::Example).unwrap();
+    let machines = parse(&example).unwrap();
+
+    let result: isize = machines.iter().filter_map(|x| x.calculate(0)).sum();
+    assert_eq!(result, 480);
+}
+
+#[test]
+fn test_truncated() {
+    let error = parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\n").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "record 1, line 3, column 1: expected line 3 of 3, found nothing"
+    );
+}
@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+
+use shared::{
+    error::ParseError,
+    image::{Image, Rgb},
+    math::crt,
+    parse::ints_n,
+    rect::Rect,
+    render::{Canvas, Colour},
+    sim::Simulation,
+    solution::{Answer, Solution},
+    vector::Vector,
+};
+
+#[cfg(test)]
+use shared::input::{self, Kind};
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    position: Vector,
+    velocity: Vector,
+}
+
+impl Robot {
+    fn navigate(&mut self, map: &Rect, for_seconds: isize) {
+        self.position = map
+            .wrap(self.position + (self.velocity * for_seconds))
+            .expect("robots should move on a map with an area");
+    }
+}
+
+impl Robot {
+    fn parse(index: usize, input: &str) -> Result<Robot, ParseError> {
+        let [p_x, p_y, v_x, v_y] = ints_n(input)
+            .ok_or_else(|| ParseError::at(index, input, input, "p=<x>,<y> v=<x>,<y>"))?;
+        Ok(Robot {
+            position: (p_x, p_y).into(),
+            velocity: (v_x, v_y).into(),
+        })
+    }
+}
+
+fn parse_robots(input: &str) -> Result<Vec<Robot>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Robot::parse(index, line))
+        .collect()
+}
+
+fn canvas(robots: &[Robot], map: &Rect) -> Canvas {
+    let mut counts: HashMap<Vector, u32> = HashMap::new();
+    for robot in robots {
+        *counts.entry(robot.position).or_default() += 1;
+    }
+    Canvas::new(*map, '.').overlay(
+        counts
+            .into_iter()
+            .map(|(position, count)| (position, char::from_digit(count, 10).unwrap_or('+'))),
+    )
+}
+
+#[cfg(test)]
+fn output(robots: &[Robot], map: &Rect) -> String {
+    canvas(robots, map).to_string()
+}
+
+fn count_robots_in_quadrant(robots: &[Robot], quadrant: &Rect) -> usize {
+    robots
+        .iter()
+        .filter(|x| quadrant.contains(&x.position))
+        .count()
+}
+
+fn get_safety_factor(robots: &[Robot], map: &Rect) -> usize {
+    map.split_quadrants()
+        .iter()
+        .map(|quadrant| count_robots_in_quadrant(robots, quadrant))
+        .product()
+}
+
+/// How spread out `values` are, the variance times the square of their count.
+fn spread(values: impl Iterator<Item = isize>) -> isize {
+    let (count, sum, squares) = values.fold((0, 0, 0), |(count, sum, squares), x| {
+        (count + 1, sum + x, squares + x * x)
+    });
+    count * squares - sum * sum
+}
+
+/// Seconds until the robots first arrange into the picture. The picture packs the robots
+/// into a few columns and a few rows. Columns repeat every `map.width` seconds and rows every
+/// `map.height` seconds, so the tightest second of each is found on its own and the CRT gives
+/// the second where both are tight. None without robots, when the map has no area or when no
+/// second is much tighter than the rest, as then there is no picture.
+fn find_picture(robots: &[Robot], map: &Rect) -> Option<usize> {
+    if robots.is_empty() {
+        return None;
+    }
+    let tightest = |period: isize, axis: fn(&Vector) -> isize, start: isize| {
+        let spreads: Vec<isize> = (0..period)
+            .map(|seconds| {
+                spread(robots.iter().map(|robot| {
+                    (axis(&robot.position) - start + axis(&robot.velocity) * seconds)
+                        .rem_euclid(period)
+                }))
+            })
+            .collect();
+        let (seconds, tightest) = spreads.iter().enumerate().min_by_key(|x| *x.1)?;
+        let average = spreads.iter().sum::<isize>() / period;
+        // Scattered robots stay close to the average every second
+        (tightest * 4 < average * 3).then_some(seconds as isize)
+    };
+    let x_seconds = tightest(map.width, |x| x.x, map.x)?;
+    let y_seconds = tightest(map.height, |x| x.y, map.y)?;
+    crt(&[(x_seconds, map.width), (y_seconds, map.height)])
+        .ok()
+        .map(|(seconds, _)| seconds as usize)
+}
+
+const MAP: Rect = Rect::new(0, 0, 101, 103);
+
+/// Moves every robot one second per step, for as long as wanted.
+pub struct RobotSimulation {
+    robots: Vec<Robot>,
+    map: Rect,
+    seconds: usize,
+}
+
+impl RobotSimulation {
+    /// Uses the size of the real map, the example needs `with_map`.
+    pub fn new(input: &str) -> Result<RobotSimulation, ParseError> {
+        Ok(RobotSimulation {
+            robots: parse_robots(input)?,
+            map: MAP,
+            seconds: 0,
+        })
+    }
+
+    pub fn with_map(mut self, map: Rect) -> RobotSimulation {
+        self.map = map;
+        self
+    }
+}
+
+impl Simulation for RobotSimulation {
+    fn step(&mut self) -> bool {
+        let map = self.map;
+        self.robots.iter_mut().for_each(|x| x.navigate(&map, 1));
+        self.seconds += 1;
+        true
+    }
+
+    fn undo(&mut self) -> bool {
+        if self.seconds == 0 {
+            return false;
+        }
+        let map = self.map;
+        self.robots.iter_mut().for_each(|x| x.navigate(&map, -1));
+        self.seconds -= 1;
+        true
+    }
+
+    fn render(&self, overlays: &[bool]) -> Canvas {
+        let mut canvas = canvas(&self.robots, &self.map);
+        if overlays[0] {
+            let colours = [Colour::Red, Colour::Green, Colour::Blue, Colour::Yellow];
+            for (quadrant, colour) in self.map.split_quadrants().iter().zip(colours) {
+                canvas = canvas.highlight(quadrant.points(), colour);
+            }
+        }
+        canvas
+    }
+
+    fn overlays(&self) -> &'static [&'static str] {
+        &["quadrants"]
+    }
+
+    /// Robots in green, a lot easier to look through than text frames.
+    fn frame(&self) -> Image {
+        let positions: Vec<Vector> = self.robots.iter().map(|x| x.position).collect();
+        Image::from_points(self.map, &positions, 4, Rgb(0, 200, 0), Rgb::BLACK)
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_robots(input)
+    }
+
+    fn part_one(robots: &Self::Input) -> Answer {
+        let mut robots = robots.clone();
+        robots.iter_mut().for_each(|x| x.navigate(&MAP, 100));
+        get_safety_factor(&robots, &MAP).into()
+    }
+
+    fn part_two(robots: &Self::Input) -> Answer {
+        find_picture(robots, &MAP).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[test]
+fn test_robot_teleport() {
+    let mut robot = Robot {
+        position: (2, 4).into(),
+        velocity: (2, -3).into(),
+    };
+    let map = Rect::new(0, 0, 11, 7);
+    robot.navigate(&map, 1);
+
+    assert_eq!(robot.position, (4, 1).into());
+
+    robot.navigate(&map, 1);
+    assert_eq!(robot.position, (6, 5).into());
+
+    robot.navigate(&map, 1);
+    assert_eq!(robot.position, (8, 2).into());
+
+    robot.navigate(&map, 1);
+    assert_eq!(robot.position, (10, 6).into());
+
+    robot.navigate(&map, 1);
+    assert_eq!(robot.position, (1, 3).into());
+}
+
+#[test]
+fn test_example_part1() {
+    let example = input::read(14, Kind::Example).unwrap();
+    let mut robots = parse_robots(&example).unwrap();
+    let map = Rect::new(0, 0, 11, 7);
+
+    robots.iter_mut().for_each(|x| x.navigate(&map, 100));
+
+    let [top_left, top_right, bottom_left, bottom_right] = map
+        .split_quadrants()
+        .map(|quadrant| count_robots_in_quadrant(&robots, &quadrant));
+
+    assert_eq!(top_left, 1);
+    assert_eq!(top_right, 3);
+    assert_eq!(bottom_left, 4);
+    assert_eq!(bottom_right, 1);
+
+    assert_eq!(get_safety_factor(&robots, &map), 12);
+    assert_eq!(
+        output(&robots, &map),
+        "......2..1.\n...........\n1..........\n.11........\n.....1.....\n...12......\n.1....1....\n"
+    );
+}
+
+#[test]
+fn test_modulo() {
+    assert_eq!((-2isize).rem_euclid(5), 3);
+}
+
+#[test]
+fn test_invalid_robot() {
+    let error = parse_robots("p=0,4 v=3,-3\np=6,3 v=-1").unwrap_err();
+    assert_eq!(error.line, 2);
+    assert_eq!(error.expected, "p=<x>,<y> v=<x>,<y>");
+}
+
+#[test]
+fn test_simulation() {
+    let example = input::read(14, Kind::Example).unwrap();
+    let map = Rect::new(0, 0, 11, 7);
+    let mut simulation = RobotSimulation::new(&example).unwrap().with_map(map);
+    let start = simulation.render(&[false]);
+    for _ in 0..100 {
+        simulation.step();
+    }
+
+    assert_eq!(
+        simulation.render(&[false]).to_string(),
+        output(&simulation.robots, &map)
+    );
+    let quadrants = simulation.render(&[true]);
+    let colour = |x, y| {
+        quadrants
+            .get(Vector::new(x, y))
+            .and_then(|cell| cell.colour)
+    };
+    assert_eq!(colour(0, 0), Some(Colour::Red));
+    assert_eq!(colour(10, 0), Some(Colour::Green));
+    assert_eq!(colour(0, 6), Some(Colour::Blue));
+    assert_eq!(colour(10, 6), Some(Colour::Yellow));
+    assert_eq!(colour(5, 0), None);
+    assert_eq!(colour(0, 3), None);
+    assert!(quadrants
+        .colours()
+        .to_string()
+        .starts_with("\x1b[31m.\x1b[0m"));
+    while simulation.undo() {}
+    assert_eq!(simulation.render(&[false]), start);
+
+    let frame = simulation.frame();
+    let robot = simulation.robots[0].position;
+    assert_eq!((frame.width, frame.height), (44, 28));
+    assert_eq!(
+        frame.get(robot.x as usize * 4, robot.y as usize * 4),
+        Some(Rgb(0, 200, 0))
+    );
+}
+
+#[test]
+fn test_find_picture() {
+    // Draws a square at `seconds` and scatters the rest, then winds every robot back
+    let seconds = 6789;
+    let mut random = 12345u64;
+    let mut next = |range: isize| {
+        random = random
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (random >> 33) as isize % range
+    };
+    let robots: Vec<Robot> = (0..500)
+        .map(|index| {
+            let picture = Vector::new(40 + index % 15, 30 + index / 15 % 15);
+            let noise = Vector::new(next(MAP.width), next(MAP.height));
+            let velocity = Vector::new(next(201) - 100, next(201) - 100);
+            let end = if index < 225 { picture } else { noise };
+            Robot {
+                position: MAP.wrap(end - velocity * seconds).unwrap(),
+                velocity,
+            }
+        })
+        .collect();
+
+    assert_eq!(find_picture(&robots, &MAP), Some(seconds as usize));
+    assert_eq!(find_picture(&[], &MAP), None);
+    assert_eq!(find_picture(&robots, &Rect::new(0, 0, 0, 103)), None);
+
+    let scattered: Vec<Robot> = robots
+        .iter()
+        .map(|robot| Robot {
+            position: Vector::new(next(MAP.width), next(MAP.height)),
+            velocity: robot.velocity,
+        })
+        .collect();
+    assert_eq!(find_picture(&scattered, &MAP), None);
+}
@@ -0,0 +1,97 @@
+use regex::Regex;
+use shared::{
+    error::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+fn find_muls(string: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    let regex = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+    let mut results = vec![];
+
+    // A mul never spans lines, so matching line by line finds the same ones
+    for (index, line) in string.lines().enumerate() {
+        for (_, [first, second]) in regex.captures_iter(line).map(|c| c.extract()) {
+            results.push((
+                error::number(index, line, first)?,
+                error::number(index, line, second)?,
+            ));
+        }
+    }
+    Ok(results)
+}
+
+/// Whitespace is removed first so disabled parts can span lines, so positions in errors are
+/// in that text.
+fn find_muls_part2(string: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    let string_without_whitespace = string
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>();
+    let regex = Regex::new(r"don't\(\)(.+?)do\(\)").unwrap();
+
+    let cleaned = regex.replace_all(&string_without_whitespace, "");
+    find_muls(cleaned.as_ref())
+}
+
+fn add_up(values: &[(usize, usize)]) -> usize {
+    values.iter().fold(0usize, |acc, e| acc + (e.0 * e.1))
+}
+
+/// The multiplications of the whole memory and the ones that are enabled.
+#[derive(Debug)]
+pub struct Memory {
+    muls: Vec<(usize, usize)>,
+    enabled: Vec<(usize, usize)>,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Memory;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Memory {
+            muls: find_muls(input)?,
+            enabled: find_muls_part2(input)?,
+        })
+    }
+
+    fn part_one(memory: &Self::Input) -> Answer {
+        add_up(&memory.muls).into()
+    }
+
+    fn part_two(memory: &Self::Input) -> Answer {
+        add_up(&memory.enabled).into()
+    }
+}
+
+#[test]
+fn test_find_muls() {
+    let result =
+        find_muls("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))");
+
+    assert_eq!(result, Ok(vec![(2, 4), (5, 5), (11, 8), (8, 5)]))
+}
+
+#[test]
+fn test_find_muls_part2() {
+    let result = find_muls_part2(
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+    );
+
+    assert_eq!(result, Ok(vec![(2, 4), (8, 5)]))
+}
+#[test]
+fn test_add_up() {
+    let values = vec![(2, 4), (5, 5), (11, 8), (8, 5)];
+    assert_eq!(add_up(&values), 161);
+}
+
+#[test]
+fn test_overflow() {
+    let error = find_muls("mul(2,4)\nxmul(99999999999999999999,1)").unwrap_err();
+    assert_eq!(
+        error,
+        ParseError::new(2, 6, "99999999999999999999", "a number")
+    );
+}
@@ -0,0 +1,135 @@
+use shared::{
+    error::ParseError,
+    grid::Grid,
+    solution::{Answer, Solution},
+    vector::Vector,
+};
+
+#[cfg(test)]
+use shared::input::{self, Kind};
+
+fn collect_string(grid: &Grid<char>, starting_point: Vector, vector: Vector) -> String {
+    let mut result = String::new();
+    let mut cursor = starting_point;
+    while let Some(char) = grid.get(&cursor) {
+        result.push(*char);
+        cursor += vector;
+    }
+    result
+}
+
+fn is_mas(grid: &Grid<char>, starting_point: Vector) -> bool {
+    let mut corner_chars = vec![];
+
+    for i in 0..=8 {
+        let vec_x = i % 3 - 1;
+        let vec_y = i / 3 - 1;
+        let vector = Vector::new(vec_x, vec_y);
+        if vector.x == 0 || vector.y == 0 {
+            continue;
+        }
+
+        corner_chars.push(grid.get(&(starting_point + vector)));
+    }
+
+    let corner_chars: Vec<char> = corner_chars.into_iter().flatten().copied().collect();
+
+    if corner_chars.len() < 4 {
+        return false;
+    }
+
+    // Almost 1 AM,
+    let possible_configs: Vec<Vec<char>> = vec![
+        vec!['M', 'M', 'S', 'S'],
+        vec!['S', 'S', 'M', 'M'],
+        vec!['S', 'M', 'S', 'M'],
+        vec!['M', 'S', 'M', 'S'],
+    ];
+
+    possible_configs.contains(&corner_chars)
+}
+
+fn find_xmas_part2(grid: &Grid<char>) -> usize {
+    grid.iter()
+        .filter(|(position, char)| **char == 'A' && is_mas(grid, *position))
+        .count()
+}
+
+fn find_xmas(grid: &Grid<char>, word_to_find: &str) -> usize {
+    let mut count = 0usize;
+    let Some(first_char) = word_to_find.chars().next() else {
+        return 0;
+    };
+
+    for (position, char) in grid.iter() {
+        if *char != first_char {
+            continue;
+        }
+        for i in 0..=8 {
+            let vec_x = i % 3 - 1;
+            let vec_y = i / 3 - 1;
+            let vector = Vector::new(vec_x, vec_y);
+            if vector == Vector::new(0, 0) {
+                continue;
+            }
+            let result = collect_string(grid, position, vector);
+
+            if result.starts_with(word_to_find) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::try_parse(input, Some, "a letter")
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        find_xmas(input, "XMAS").into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        find_xmas_part2(input).into()
+    }
+}
+
+#[test]
+fn test_find_xmas() {
+    let test = input::read(4, Kind::Named("test-input")).unwrap();
+    assert_eq!(find_xmas(&parse(&test).unwrap(), "XMAS"), 18);
+}
+
+#[test]
+fn test_find_xmas_part2() {
+    let test = input::read(4, Kind::Named("test-part2")).unwrap();
+    assert_eq!(find_xmas_part2(&parse(&test).unwrap()), 9);
+}
+
+#[test]
+fn test_simple() {
+    let test = input::read(4, Kind::Named("test-simple")).unwrap();
+    assert_eq!(find_xmas(&parse(&test).unwrap(), "XMAS"), 4);
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(find_xmas(&parse("").unwrap(), "XMAS"), 0);
+
+    let error = parse("XMAS\nSAM\n").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 1: expected a line 4 tiles wide, found \"SAM\""
+    );
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use shared::{
+    error::{number, ParseError},
+    parse::{sections_n, Section},
+    solution::{Answer, Solution},
+};
+
+#[cfg(test)]
+use shared::input::{self, Kind};
+
+type Rules = HashMap<usize, Vec<usize>>;
+
+fn parse_pages(section: Section) -> Result<Vec<Vec<usize>>, ParseError> {
+    section
+        .lines()
+        .map(|line| {
+            line.text
+                .split(',')
+                .map(|x| number::<usize>(line.index, line.text, x))
+                .collect()
+        })
+        .collect()
+}
+
+fn parse_ordering_rules(section: Section) -> Result<Rules, ParseError> {
+    let mut result: Rules = HashMap::new();
+    for line in section.lines() {
+        let (first, second) = line
+            .text
+            .split_once('|')
+            .ok_or_else(|| line.error(line.text, "<page>|<page>"))?;
+
+        let first = number::<usize>(line.index, line.text, first)?;
+        let second = number::<usize>(line.index, line.text, second)?;
+
+        if let Some(rule) = result.get_mut(&first) {
+            rule.push(second);
+        } else {
+            result.insert(first, vec![second]);
+        }
+    }
+    Ok(result)
+}
+
+fn reorder(rules: &Rules, pages: &[usize]) -> Vec<usize> {
+    let mut pages = pages.to_vec();
+    pages.sort_by(|a, b| {
+        if let Some(rule) = rules.get(a) {
+            if rule.contains(b) {
+                return std::cmp::Ordering::Less;
+            } else {
+                return std::cmp::Ordering::Greater;
+            }
+        }
+        std::cmp::Ordering::Equal
+    });
+    pages
+}
+
+fn part_one(rules: &Rules, all_pages: &[Vec<usize>]) -> usize {
+    let mut count = 0usize;
+
+    for page in all_pages {
+        let reordered = reorder(rules, page);
+        let middle = reordered.get(reordered.len() / 2).unwrap();
+        if reordered == *page {
+            count += middle;
+        }
+    }
+    count
+}
+
+fn part_two(rules: &Rules, all_pages: &[Vec<usize>]) -> usize {
+    let mut count = 0usize;
+
+    for page in all_pages {
+        let reordered = reorder(rules, page);
+        let middle = reordered.get(reordered.len() / 2).unwrap();
+        if reordered != *page {
+            count += middle;
+        }
+    }
+    count
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Rules, Vec<Vec<usize>>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let [rules, pages] = sections_n(input)?;
+        Ok((parse_ordering_rules(rules)?, parse_pages(pages)?))
+    }
+
+    fn part_one((rules, all_pages): &Self::Input) -> Answer {
+        part_one(rules, all_pages).into()
+    }
+
+    fn part_two((rules, all_pages): &Self::Input) -> Answer {
+        part_two(rules, all_pages).into()
+    }
+}
+
+#[test]
+fn test_count() {
+    let input = input::read(5, Kind::Example).unwrap();
+    let (rules, all_pages) = Day5::parse(&input).unwrap();
+    assert_eq!(part_one(&rules, &all_pages), 143);
+}
+
+#[test]
+fn test_part_two() {
+    let input = input::read(5, Kind::Example).unwrap();
+    let (rules, all_pages) = Day5::parse(&input).unwrap();
+    assert_eq!(part_two(&rules, &all_pages), 123);
+}
+
+#[test]
+fn test_invalid_rule() {
+    let error = Day5::parse("47|53\n97|x3\n\n75,47").unwrap_err();
+    assert_eq!(error, ParseError::new(2, 4, "x3", "a number"));
+}
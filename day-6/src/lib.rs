@@ -0,0 +1,197 @@
+use std::{collections::HashSet, iter};
+
+use shared::{
+    cycle,
+    error::ParseError,
+    map,
+    render::{Canvas, Cell, Colour, Glyph},
+    sim::Simulation,
+    solution::{Answer, Solution},
+    vector::{Direction, Vector},
+};
+
+#[cfg(test)]
+use shared::input::{self, Kind};
+
+enum Tile {
+    Empty,
+    Obstruction,
+    OutOfBounds,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+enum PartolResult {
+    Loop,
+    Escape,
+}
+
+type Guard = (Vector, Direction);
+
+#[derive(Clone)]
+pub struct Map {
+    map: map::Map<char>,
+    guard_position: Vector,
+    guard_direction: Direction,
+}
+
+impl Map {
+    fn from(input: &str) -> Result<Map, ParseError> {
+        let map = map::Map::try_parse(
+            input,
+            |char| matches!(char, '.' | '#' | '^').then_some(char),
+            "'.', '#' or '^'",
+        )?;
+        let guard_position = *map
+            .iter()
+            .find(|x| *x.1 == '^')
+            .ok_or_else(|| ParseError::end(input, "a guard '^'"))?
+            .0;
+        Ok(Map {
+            map,
+            guard_position,
+            guard_direction: Direction::Up,
+        })
+    }
+
+    // None once the guard has left the map
+    fn guard_step(&self, (position, direction): &Guard) -> Option<Guard> {
+        let next_pos = *position + *direction;
+        match self.check_next_position(&next_pos) {
+            Tile::Empty => Some((next_pos, *direction)),
+            Tile::Obstruction => Some((*position, direction.turn_right())),
+            Tile::OutOfBounds => None,
+        }
+    }
+
+    fn guard_partol(&self) -> PartolResult {
+        let start = Some((self.guard_position, self.guard_direction));
+        let cycle_length = cycle::length(start, |guard| {
+            guard.and_then(|guard| self.guard_step(&guard))
+        });
+        // Leaving the map is the only state that steps to itself
+        match cycle_length {
+            1 => PartolResult::Escape,
+            _ => PartolResult::Loop,
+        }
+    }
+
+    fn visited_positions(&self) -> HashSet<Vector> {
+        let start = (self.guard_position, self.guard_direction);
+        iter::successors(Some(start), |guard| self.guard_step(guard))
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    fn check_next_position(&self, next_pos: &Vector) -> Tile {
+        let position = self.map.get(next_pos);
+        match position {
+            Some(char) if *char == '#' => Tile::Obstruction,
+            Some(_) => Tile::Empty,
+            None => Tile::OutOfBounds,
+        }
+    }
+}
+
+/// Walks the guard one step at a time, turning counts as a step.
+pub struct GuardSimulation {
+    map: Map,
+    path: Vec<Guard>,
+}
+
+impl GuardSimulation {
+    pub fn new(input: &str) -> Result<GuardSimulation, ParseError> {
+        let map = Map::from(input)?;
+        let start = (map.guard_position, map.guard_direction);
+        Ok(GuardSimulation {
+            map,
+            path: vec![start],
+        })
+    }
+}
+
+impl Simulation for GuardSimulation {
+    fn step(&mut self) -> bool {
+        let guard = self.path[self.path.len() - 1];
+        match self.map.guard_step(&guard) {
+            Some(next) => {
+                self.path.push(next);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn undo(&mut self) -> bool {
+        self.path.len() > 1 && self.path.pop().is_some()
+    }
+
+    fn render(&self, overlays: &[bool]) -> Canvas {
+        let (position, direction) = self.path[self.path.len() - 1];
+        let mut canvas = Canvas::from_map(&self.map.map).overlay([(self.map.guard_position, '.')]);
+        if overlays[0] {
+            let path = self.path.iter().map(|(position, direction)| {
+                (*position, Cell::new(direction.glyph(), Colour::Blue))
+            });
+            canvas = canvas.overlay(path);
+        }
+        canvas.overlay([(position, Cell::new(direction.glyph(), Colour::Red))])
+    }
+
+    fn overlays(&self) -> &'static [&'static str] {
+        &["path"]
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::from(input)
+    }
+
+    fn part_one(map: &Self::Input) -> Answer {
+        map.visited_positions().len().into()
+    }
+
+    fn part_two(map: &Self::Input) -> Answer {
+        let mut count = 0usize;
+        for tile in map.map.iter() {
+            if *tile.1 != '.' {
+                continue;
+            }
+            let mut simulation_map = map.clone();
+            simulation_map.map.insert(*tile.0, '#');
+            if simulation_map.guard_partol() == PartolResult::Loop {
+                count += 1;
+            }
+        }
+        count.into()
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = input::read(6, Kind::Example).unwrap();
+    let example = Map::from(&input).unwrap();
+    assert_eq!(example.visited_positions().len(), 41);
+    assert_eq!(example.guard_partol(), PartolResult::Escape);
+}
+
+#[test]
+fn test_simulation() {
+    let input = input::read(6, Kind::Example).unwrap();
+    let mut simulation = GuardSimulation::new(&input).unwrap();
+    while simulation.step() {}
+    let visited: HashSet<Vector> = simulation.path.iter().map(|x| x.0).collect();
+
+    assert_eq!(visited.len(), 41);
+    assert!(simulation.undo());
+    while simulation.undo() {}
+    assert_eq!(simulation.path.len(), 1);
+    assert_eq!(
+        simulation.render(&[false]).to_string().lines().nth(6),
+        Some(".#..^.....")
+    );
+}
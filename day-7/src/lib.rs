@@ -0,0 +1,157 @@
+use std::cmp::max;
+
+use shared::{
+    error::{number, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[cfg(test)]
+use shared::input::{self, Kind};
+
+#[derive(Debug)]
+pub struct Equation {
+    result: usize,
+    nums: Vec<usize>,
+}
+
+fn concat(a: usize, b: usize) -> usize {
+    (a.to_string() + &b.to_string()).parse().unwrap()
+}
+
+fn calculate(eq: &Equation, cumulative_result: usize, at: usize, part2: bool) -> bool {
+    if cumulative_result > eq.result {
+        return false;
+    }
+
+    let Some(num) = eq.nums.get(at) else {
+        return cumulative_result == eq.result;
+    };
+
+    if calculate(eq, cumulative_result + num, at + 1, part2) {
+        return true;
+    }
+
+    if calculate(eq, max(cumulative_result, 1) * num, at + 1, part2) {
+        return true;
+    }
+    if part2 && calculate(eq, concat(cumulative_result, *num), at + 1, part2) {
+        return true;
+    }
+
+    false
+}
+
+fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
+    let mut result = vec![];
+
+    for (index, line) in input.lines().enumerate() {
+        let (e_result, numbers) = line.split_once(':').ok_or_else(|| {
+            ParseError::at(index, line, line, "a result and numbers split by ':'")
+        })?;
+        result.push(Equation {
+            result: number(index, line, e_result)?,
+            nums: numbers
+                .split_whitespace()
+                .map(|x| number::<usize>(index, line, x))
+                .collect::<Result<Vec<usize>, ParseError>>()?,
+        });
+    }
+    Ok(result)
+}
+
+fn sum_solvable(equations: &[Equation], part2: bool) -> usize {
+    equations
+        .iter()
+        .filter_map(|x| {
+            if calculate(x, 0, 0, part2) {
+                Some(x.result)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        sum_solvable(input, false).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        sum_solvable(input, true).into()
+    }
+}
+
+#[test]
+fn test_equations() {
+    assert!(calculate(
+        &Equation {
+            result: 190,
+            nums: vec![10, 19]
+        },
+        0,
+        0,
+        false
+    ));
+
+    assert!(calculate(
+        &Equation {
+            result: 3267,
+            nums: vec![81, 40, 27]
+        },
+        0,
+        0,
+        false
+    ))
+}
+
+#[test]
+fn test_concat() {
+    assert_eq!(concat(214, 324), 214324);
+}
+
+#[test]
+fn test_part1() {
+    let input = input::read(7, Kind::Example).unwrap();
+    let parsed = parse(&input).unwrap();
+    println!("parsed {:?}", parsed);
+    let results: usize = parsed
+        .iter()
+        .map(|x| {
+            if calculate(x, 0, 0, false) {
+                x.result
+            } else {
+                0
+            }
+        })
+        .sum();
+
+    assert_eq!(results, 3749);
+}
+
+#[test]
+fn test_part2() {
+    let input = input::read(7, Kind::Example).unwrap();
+    let parsed = parse(&input).unwrap();
+    println!("parsed {:?}", parsed);
+    let results: usize = parsed
+        .iter()
+        .filter_map(|x| {
+            if calculate(x, 0, 0, true) {
+                Some(x.result)
+            } else {
+                None
+            }
+        })
+        .sum();
+
+    assert_eq!(results, 11387);
+}
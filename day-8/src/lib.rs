@@ -0,0 +1,160 @@
+use std::collections::{HashMap, HashSet};
+
+use shared::{
+    error::ParseError,
+    grid::Grid,
+    rect::Rect,
+    solution::{Answer, Solution},
+    vector::Vector,
+};
+
+#[cfg(test)]
+use shared::input::{self, Kind};
+
+type AntennaMap = HashMap<char, Vec<Vector>>;
+
+fn is_antenna(ch: char) -> bool {
+    ch.is_ascii_digit() || ch.is_ascii_alphabetic()
+}
+
+/// Where every antenna is, grouped by frequency.
+#[derive(Debug)]
+pub struct Antennas {
+    bounds: Rect,
+    map: AntennaMap,
+}
+
+fn parse(input: &str) -> Result<Antennas, ParseError> {
+    let grid = Grid::try_parse(
+        input,
+        |char| match char {
+            '.' => Some(None),
+            char if is_antenna(char) => Some(Some(char)),
+            _ => None,
+        },
+        "an antenna or '.'",
+    )?;
+
+    let mut map: AntennaMap = HashMap::new();
+    for (position, tile) in grid.iter() {
+        if let Some(char) = tile {
+            map.entry(*char).or_default().push(position);
+        }
+    }
+    Ok(Antennas {
+        bounds: Rect::new(0, 0, grid.width, grid.height),
+        map,
+    })
+}
+
+fn solution(antennas: &Antennas, bounce: bool) -> usize {
+    let bounds = antennas.bounds;
+
+    let mut antinodes: HashSet<Vector> = HashSet::new();
+
+    for positions in antennas.map.values() {
+        for position in positions {
+            for second_antenna_position in positions {
+                if second_antenna_position == position {
+                    continue;
+                }
+                let delta = match bounce {
+                    true => position.delta(second_antenna_position),
+                    false => position.delta(second_antenna_position).mirror(),
+                };
+                let mut antinode_position = *position;
+                loop {
+                    antinode_position += delta;
+                    if bounds.contains(&antinode_position) {
+                        antinodes.insert(antinode_position);
+                        if !bounce {
+                            break;
+                        } else {
+                            antinodes.insert(*position);
+                            antinodes.insert(*second_antenna_position);
+                        }
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    antinodes.len()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Antennas;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solution(input, false).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solution(input, true).into()
+    }
+}
+
+#[test]
+fn test_is_antenna() {
+    assert!(is_antenna('a'));
+    assert!(is_antenna('D'));
+    assert!(is_antenna('9'));
+    assert!(is_antenna('0'));
+    assert!(!is_antenna('.'));
+}
+
+#[test]
+fn test_bounds() {
+    let example = input::read(8, Kind::Example).unwrap();
+    let bounds = Rect::from_text(&example);
+    assert_eq!(bounds.x, 0);
+    assert_eq!(bounds.y, 0);
+    assert_eq!(bounds.height, 12);
+    assert_eq!(bounds.width, 12);
+
+    assert!(bounds.contains(&Vector::new(0, 0)));
+    assert!(!bounds.contains(&Vector::new(12, 12)));
+    assert!(bounds.contains(&Vector::new(11, 11)));
+    assert!(bounds.contains(&Vector::new(5, 5)));
+}
+
+#[test]
+fn test_part_one_example() {
+    let example = input::read(8, Kind::Example).unwrap();
+    let count = solution(&parse(&example).unwrap(), false);
+    assert_eq!(count, 14);
+}
+
+#[test]
+fn test_part_two_example() {
+    let example = input::read(8, Kind::Example).unwrap();
+    let count = solution(&parse(&example).unwrap(), true);
+    assert_eq!(count, 34);
+}
+#[test]
+fn test_parse() {
+    let error = parse("..a\n.#.\n").unwrap_err();
+    assert_eq!(error, ParseError::new(2, 2, "#", "an antenna or '.'"));
+
+    let error = parse("..a\n..\n").unwrap_err();
+    assert_eq!(error.expected, "a line 3 tiles wide");
+}
+
+#[test]
+fn mirror() {
+    let antenna1 = Vector::new(7, 7);
+    let antenna2 = Vector::new(10, 10);
+
+    let delta = antenna1.delta(&antenna2);
+
+    let mirror = antenna1 + delta.mirror();
+
+    assert_eq!(mirror, Vector::new(4, 4));
+}
@@ -0,0 +1,205 @@
+use shared::{
+    error::ParseError,
+    interval::{Interval, IntervalSet},
+    solution::{Answer, Solution},
+};
+
+/// Where each file is, in the order they appear, and which blocks are free.
+fn layout(disk: &Disk) -> (Vec<(Id, Interval<usize>)>, IntervalSet<usize>) {
+    let mut files: Vec<(Id, Interval<usize>)> = vec![];
+    let mut free = IntervalSet::new();
+    for (index, block) in disk.iter().enumerate() {
+        let interval = Interval::new(index, index + 1);
+        match block {
+            Some(id) => match files.last_mut() {
+                Some((last, file)) if last == id => file.end += 1,
+                _ => files.push((*id, interval)),
+            },
+            None => free.insert(interval),
+        }
+    }
+    (files, free)
+}
+
+fn compress_part2(mut disk: Disk) -> Disk {
+    let (files, mut free) = layout(&disk);
+    for (id, file) in files.into_iter().rev() {
+        let Some(space) = free.first_fit(file.len()).filter(|x| x.start < file.start) else {
+            continue;
+        };
+
+        disk[space.start..space.end].fill(Some(id));
+        disk[file.start..file.end].fill(None);
+        free.remove(space);
+        free.insert(file);
+    }
+    disk
+}
+
+fn compress(mut disk: Disk) -> Disk {
+    let mut i = 0usize;
+    'outer: while let Some(item) = disk.get(i) {
+        if item.is_some() {
+            i += 1;
+            continue;
+        }
+
+        let last: Id = {
+            loop {
+                if i > disk.len() - 1 {
+                    break 'outer;
+                }
+                let Some(last_item) = disk.pop() else {
+                    break 'outer;
+                };
+                match last_item {
+                    Some(l) => break l,
+                    None => continue,
+                }
+            }
+        };
+
+        disk[i] = Some(last);
+
+        i += 1;
+    }
+
+    disk
+}
+
+type Id = usize;
+
+pub type Disk = Vec<Option<Id>>;
+
+fn create_disk(input: &str) -> Result<Disk, ParseError> {
+    let mut id = 0usize;
+    let mut switch = true;
+    let mut disk: Vec<Option<Id>> = vec![];
+
+    for (offset, char) in input.char_indices() {
+        let num = char.to_digit(10).ok_or_else(|| {
+            ParseError::at(
+                0,
+                input,
+                &input[offset..offset + char.len_utf8()],
+                "a digit",
+            )
+        })?;
+        for _ in 0..num {
+            match switch {
+                true => disk.push(Some(id)),
+                false => disk.push(None),
+            };
+        }
+        if switch {
+            id += 1;
+        }
+        switch = !switch;
+    }
+    Ok(disk)
+}
+
+fn checksum(input: Disk) -> usize {
+    let mut result = 0;
+    for (i, item) in input.iter().enumerate() {
+        if let Some(num) = item {
+            result += i * *num;
+        } else {
+            continue;
+        }
+    }
+    result
+}
+
+fn process(disk: &Disk, part2: bool) -> usize {
+    let disk = disk.clone();
+    let compress = match part2 {
+        true => compress_part2(disk),
+        false => compress(disk),
+    };
+    checksum(compress)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Disk;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        create_disk(input.trim())
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        process(input, false).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        process(input, true).into()
+    }
+}
+
+#[cfg(test)]
+fn disk_to_string(disk: &Disk) -> String {
+    let mut result = "".to_owned();
+    for i in disk {
+        match i {
+            Some(num) => result += &num.to_string(),
+            None => result += ".",
+        }
+    }
+    result
+}
+
+#[test]
+fn test_example() {
+    let example = "2333133121414131402";
+    let result = create_disk(example).unwrap();
+
+    assert_eq!(
+        disk_to_string(&result),
+        "00...111...2...333.44.5555.6666.777.888899"
+    );
+}
+
+#[test]
+fn test_part_one_example() {
+    let example = "2333133121414131402";
+    let checksum = process(&create_disk(example).unwrap(), false);
+    assert_eq!(checksum, 1928)
+}
+
+#[test]
+fn test_part_two_example() {
+    let example = "2333133121414131402";
+    let checksum = process(&create_disk(example).unwrap(), true);
+    assert_eq!(checksum, 2858)
+}
+#[test]
+fn test_edge_case() {
+    let edge_case = "1010101010101010101010";
+    let checksum = process(&create_disk(edge_case).unwrap(), false);
+    assert_eq!(checksum, 385);
+}
+
+#[test]
+fn test_edge_case2() {
+    let edge_case = "12345";
+    let checksum = process(&create_disk(edge_case).unwrap(), false);
+    assert_eq!(checksum, 60);
+
+    let checksum = process(&create_disk(edge_case).unwrap(), true);
+    assert_eq!(checksum, 132);
+}
+
+#[test]
+fn test_invalid_digit() {
+    let error = create_disk("12x4").unwrap_err();
+    assert_eq!(error, ParseError::new(1, 3, "x", "a digit"));
+}
+
+#[test]
+fn test_edge_case3() {
+    let edge_case = "14113";
+    let checksum = process(&create_disk(edge_case).unwrap(), true);
+    assert_eq!(checksum, 16);
+}
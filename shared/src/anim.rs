@@ -0,0 +1,162 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::image::Image;
+
+/// Keeps one frame every `every` steps of a simulation, at most `max_frames` of them, so a
+/// long run still fits in one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recorder {
+    every: usize,
+    max_frames: Option<usize>,
+    step: usize,
+    frames: Vec<Image>,
+}
+
+impl Recorder {
+    /// Keeps every step.
+    pub fn new() -> Recorder {
+        Recorder {
+            every: 1,
+            max_frames: None,
+            step: 0,
+            frames: vec![],
+        }
+    }
+
+    /// Only keeps steps 0, `every`, 2 * `every` and so on.
+    pub fn every(mut self, every: usize) -> Recorder {
+        self.every = every.max(1);
+        self
+    }
+
+    /// Stops keeping frames once there are `max_frames`.
+    pub fn max_frames(mut self, max_frames: usize) -> Recorder {
+        self.max_frames = Some(max_frames);
+        self
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.max_frames.is_some_and(|x| self.frames.len() >= x)
+    }
+
+    /// Counts one step. `render` is only called when the step is kept, so skipped steps cost
+    /// nothing. Returns whether a frame was kept.
+    pub fn record(&mut self, render: impl FnOnce() -> Image) -> bool {
+        let keep = self.step.is_multiple_of(self.every) && !self.is_full();
+        self.step += 1;
+        if keep {
+            self.frames.push(render());
+        }
+        keep
+    }
+
+    pub fn frames(&self) -> &[Image] {
+        &self.frames
+    }
+
+    /// Writes `<prefix>-0000.ppm`, `<prefix>-0001.ppm` and so on into `dir`.
+    pub fn write_ppm_sequence(&self, dir: &Path, prefix: &str) -> io::Result<Vec<PathBuf>> {
+        fs::create_dir_all(dir)?;
+        let mut written = vec![];
+        for (index, frame) in self.frames.iter().enumerate() {
+            let path = dir.join(format!("{}-{:04}.ppm", prefix, index));
+            fs::write(&path, frame.to_ppm())?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+
+    /// Animated GIF that loops forever, showing each frame for `delay` hundredths of a
+    /// second. Every frame should be as large as the first one.
+    #[cfg(feature = "gif")]
+    pub fn to_gif(&self, delay: u16) -> io::Result<Vec<u8>> {
+        let Some(first) = self.frames.first() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "there are no frames to write",
+            ));
+        };
+        let (Ok(width), Ok(height)) = (u16::try_from(first.width), u16::try_from(first.height))
+        else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "a GIF can not be {}x{}, at most 65535 pixels each way",
+                    first.width, first.height
+                ),
+            ));
+        };
+        let mut bytes = vec![];
+        {
+            let mut encoder =
+                gif::Encoder::new(&mut bytes, width, height, &[]).map_err(io::Error::other)?;
+            encoder
+                .set_repeat(gif::Repeat::Infinite)
+                .map_err(io::Error::other)?;
+            for frame in &self.frames {
+                if (frame.width, frame.height) != (first.width, first.height) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "frames should all have the same size",
+                    ));
+                }
+                let mut frame = gif::Frame::from_rgb_speed(width, height, &frame.rgb_bytes(), 10);
+                frame.delay = delay;
+                encoder.write_frame(&frame).map_err(io::Error::other)?;
+            }
+        }
+        Ok(bytes)
+    }
+
+    #[cfg(feature = "gif")]
+    pub fn write_gif(&self, path: &Path, delay: u16) -> io::Result<()> {
+        fs::write(path, self.to_gif(delay)?)
+    }
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Recorder::new()
+    }
+}
+
+#[cfg(test)]
+use crate::image::Rgb;
+
+#[test]
+fn test_record() {
+    let mut recorder = Recorder::new().every(3).max_frames(2);
+    let mut rendered = vec![];
+    for step in 0..10 {
+        recorder.record(|| {
+            rendered.push(step);
+            Image::new(1, 1, Rgb(step, 0, 0))
+        });
+    }
+
+    assert_eq!(rendered, vec![0, 3]);
+    assert!(recorder.is_full());
+    assert_eq!(recorder.frames()[1].get(0, 0), Some(Rgb(3, 0, 0)));
+}
+
+#[cfg(feature = "gif")]
+#[test]
+fn test_gif() {
+    let mut recorder = Recorder::new();
+    recorder.record(|| Image::new(2, 2, Rgb::WHITE));
+    recorder.record(|| Image::new(2, 2, Rgb::BLACK));
+
+    assert!(recorder.to_gif(10).unwrap().starts_with(b"GIF89a"));
+    assert!(Recorder::new().to_gif(10).is_err());
+
+    recorder.record(|| Image::new(3, 2, Rgb::BLACK));
+    assert!(recorder.to_gif(10).is_err());
+
+    let mut recorder = Recorder::new();
+    recorder.record(|| Image::new(65536, 1, Rgb::BLACK));
+    let error = recorder.to_gif(10).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+}
@@ -0,0 +1,208 @@
+use std::collections::VecDeque;
+
+use crate::{grid::Grid, vector::Vector};
+
+/// Connected components of a grid. Components are numbered in the order their first cell
+/// appears in row-major order, and `cells` of each component are in row-major order too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    pub labels: Grid<usize>,
+    pub cells: Vec<Vec<Vector>>,
+}
+
+impl Components {
+    fn from_labels(labels: Grid<usize>, count: usize) -> Components {
+        let mut cells = vec![vec![]; count];
+        for (position, label) in labels.iter() {
+            cells[*label].push(position);
+        }
+        Components { labels, cells }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+}
+
+/// All cells reachable from `start` through neighbours for which `connected` returns true.
+/// Iterative, so large areas do not overflow the stack.
+pub fn flood_fill<T>(
+    grid: &Grid<T>,
+    start: Vector,
+    diagonals: bool,
+    mut connected: impl FnMut(&T, &T) -> bool,
+) -> Vec<Vector> {
+    let mut visited = Grid::new(grid.width, grid.height, false);
+    fill(grid, start, diagonals, &mut connected, &mut visited)
+}
+
+fn fill<T>(
+    grid: &Grid<T>,
+    start: Vector,
+    diagonals: bool,
+    connected: &mut impl FnMut(&T, &T) -> bool,
+    visited: &mut Grid<bool>,
+) -> Vec<Vector> {
+    let mut result = vec![];
+    let mut queue = VecDeque::from([start]);
+    visited[start] = true;
+
+    while let Some(position) = queue.pop_front() {
+        result.push(position);
+        for (neighbour, tile) in grid.neighbours(position, diagonals) {
+            if !visited[neighbour] && connected(&grid[position], tile) {
+                visited[neighbour] = true;
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    result
+}
+
+/// Labels connected components with repeated flood fills.
+pub fn label<T>(
+    grid: &Grid<T>,
+    diagonals: bool,
+    mut connected: impl FnMut(&T, &T) -> bool,
+) -> Components {
+    let mut visited = Grid::new(grid.width, grid.height, false);
+    let mut labels = Grid::new(grid.width, grid.height, 0);
+    let mut count = 0;
+
+    for start in grid.keys() {
+        if visited[start] {
+            continue;
+        }
+        for position in fill(grid, start, diagonals, &mut connected, &mut visited) {
+            labels[position] = count;
+        }
+        count += 1;
+    }
+    Components::from_labels(labels, count)
+}
+
+/// Labels connected components with a union-find over neighbouring cells.
+/// Gives the same result as `label` when `connected` is symmetric.
+pub fn label_union_find<T>(
+    grid: &Grid<T>,
+    diagonals: bool,
+    mut connected: impl FnMut(&T, &T) -> bool,
+) -> Components {
+    let mut sets = UnionFind::new(grid.len());
+    let index = |position: Vector| (position.y * grid.width + position.x) as usize;
+
+    for (position, tile) in grid.iter() {
+        for (neighbour, other) in grid.neighbours(position, diagonals) {
+            if connected(tile, other) {
+                sets.union(index(position), index(neighbour));
+            }
+        }
+    }
+
+    let mut labels = Grid::new(grid.width, grid.height, 0);
+    let mut set_labels = vec![usize::MAX; grid.len()];
+    let mut count = 0;
+    for position in grid.keys() {
+        let root = sets.find(index(position));
+        if set_labels[root] == usize::MAX {
+            set_labels[root] = count;
+            count += 1;
+        }
+        labels[position] = set_labels[root];
+    }
+    Components::from_labels(labels, count)
+}
+
+/// Disjoint sets with path compression and union by size.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> UnionFind {
+        UnionFind {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+        }
+    }
+
+    pub fn find(&mut self, item: usize) -> usize {
+        let mut root = item;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut item = item;
+        while self.parents[item] != root {
+            let next = self.parents[item];
+            self.parents[item] = root;
+            item = next;
+        }
+        root
+    }
+
+    /// Returns false if both were already in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let mut a = self.find(a);
+        let mut b = self.find(b);
+        if a == b {
+            return false;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        true
+    }
+
+    pub fn size(&mut self, item: usize) -> usize {
+        let root = self.find(item);
+        self.sizes[root]
+    }
+}
+
+#[test]
+fn test_flood_fill() {
+    let grid = Grid::parse("aab\nabb\nbba", |char| char);
+
+    let region = flood_fill(&grid, Vector::new(0, 0), false, |a, b| a == b);
+    assert_eq!(region.len(), 3);
+
+    let region = flood_fill(&grid, Vector::new(2, 0), true, |a, b| a == b);
+    assert_eq!(region.len(), 5);
+}
+
+#[test]
+fn test_label() {
+    let grid = Grid::parse("aab\nabb\nbba", |char| char);
+
+    let components = label(&grid, false, |a, b| a == b);
+    assert_eq!(components.len(), 3);
+    assert_eq!(
+        components.cells[0],
+        vec![Vector::new(0, 0), Vector::new(1, 0), Vector::new(0, 1)]
+    );
+    assert_eq!(components.labels[Vector::new(2, 2)], 2);
+
+    let checkers = Grid::parse("ab\nba", |char| char);
+    assert_eq!(label(&checkers, false, |a, b| a == b).len(), 4);
+    assert_eq!(label(&checkers, true, |a, b| a == b).len(), 2);
+}
+
+#[test]
+fn test_union_find_matches_flood_fill() {
+    let grid = Grid::parse("abcab\nbbbaa\ncacbc\naabbc", |char| char);
+
+    for diagonals in [false, true] {
+        assert_eq!(
+            label_union_find(&grid, diagonals, |a, b| a == b),
+            label(&grid, diagonals, |a, b| a == b)
+        );
+    }
+}
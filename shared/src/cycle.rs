@@ -0,0 +1,132 @@
+use std::{collections::HashMap, hash::Hash};
+
+// All detectors return `(mu, lambda)`: the cycle starts at step `mu` and repeats every
+// `lambda` steps. `step` has to be deterministic and the state space finite.
+
+/// Floyd's tortoise and hare. Keeps only two states in memory.
+pub fn floyd<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> (usize, usize) {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    let mut mu = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+
+    let mut lambda = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        lambda += 1;
+    }
+    (mu, lambda)
+}
+
+/// Brent's algorithm. Same memory as Floyd but fewer calls to `step`.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> (usize, usize) {
+    let lambda = length(initial.clone(), &mut step);
+
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..lambda {
+        hare = step(&hare);
+    }
+    let mut mu = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+    (mu, lambda)
+}
+
+/// Only `lambda`, from the first half of Brent's algorithm. Saves walking from the start
+/// again when where the cycle starts does not matter.
+pub fn length<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> usize {
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        hare = step(&hare);
+        lambda += 1;
+    }
+    lambda
+}
+
+/// Remembers every state, so each one is computed only once.
+pub fn find_cycle<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S) -> (usize, usize) {
+    let (states, mu) = run_until_repeat(initial, step, usize::MAX);
+    (mu, states.len() - mu)
+}
+
+/// State after `n` steps. Once a state repeats the rest is skipped using the cycle, so `n`
+/// can be far larger than the number of distinct states.
+pub fn state_at<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    let (mut states, mu) = run_until_repeat(initial, step, n);
+    if n < states.len() {
+        return states.swap_remove(n);
+    }
+    let lambda = states.len() - mu;
+    states.swap_remove(mu + (n - mu) % lambda)
+}
+
+/// Steps until a state repeats or `limit` steps were taken. Returns the states in order and
+/// the index of the state that repeated.
+fn run_until_repeat<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    limit: usize,
+) -> (Vec<S>, usize) {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+    loop {
+        if let Some(first) = seen.get(&state) {
+            return (states, *first);
+        }
+        if states.len() > limit {
+            return (states, usize::MAX);
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+#[test]
+fn test_detectors_agree() {
+    // 7 -> 3 -> 4 -> 0 -> 1 -> 2 -> 3
+    let step = |x: &usize| (x + 1) % 5;
+    assert_eq!(floyd(7, step), (1, 5));
+    assert_eq!(brent(7, step), (1, 5));
+    assert_eq!(find_cycle(7, step), (1, 5));
+
+    let step = |x: &u64| (x * x + 1) % 255;
+    let expected = find_cycle(3, step);
+    assert_eq!(floyd(3, step), expected);
+    assert_eq!(brent(3, step), expected);
+    assert_eq!(length(3, step), expected.1);
+}
+
+#[test]
+fn test_state_at() {
+    let step = |x: &usize| (x + 1) % 5;
+    assert_eq!(state_at(7, step, 0), 7);
+    assert_eq!(state_at(7, step, 2), 4);
+    assert_eq!(state_at(7, step, 1_000_000_000_000), 2);
+}
@@ -0,0 +1,108 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Where and why parsing an input failed. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The text that could not be parsed, empty when something is missing.
+    pub text: String,
+    /// What should have been there instead, like "a number" or "two numbers".
+    pub expected: String,
+    /// Blank-line separated record the line belongs to, when the input is made of records.
+    pub record: Option<usize>,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> ParseError {
+        ParseError {
+            line,
+            column,
+            text: text.into(),
+            expected: expected.into(),
+            record: None,
+        }
+    }
+
+    /// Error for `text` on the line with 0-based `index`. The column is worked out from
+    /// where `text` sits in `line`, so `text` should be a slice of `line`.
+    pub fn at(index: usize, line: &str, text: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (text.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let column = match line.get(..offset) {
+            Some(before) if offset + text.len() <= line.len() => before.chars().count() + 1,
+            _ => 1,
+        };
+        ParseError::new(index + 1, column, text, expected)
+    }
+
+    /// Error for something that is missing once the whole input has been read.
+    pub fn end(input: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(input.lines().count() + 1, 1, "", expected)
+    }
+
+    /// Marks the error as part of the record with 0-based `index`.
+    pub fn in_record(mut self, index: usize) -> ParseError {
+        self.record = Some(index + 1);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(record) = self.record {
+            write!(f, "record {}, ", record)?;
+        }
+        match self.text.as_str() {
+            "" => write!(
+                f,
+                "line {}, column {}: expected {}, found nothing",
+                self.line, self.column, self.expected
+            ),
+            text => write!(
+                f,
+                "line {}, column {}: expected {}, found {:?}",
+                self.line, self.column, self.expected, text
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `text`, a slice of the line with 0-based `index`, as a number.
+pub fn number<T: FromStr>(index: usize, line: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(index, line, text, "a number"))
+}
+
+#[test]
+fn test_column() {
+    let line = "3   x";
+    let error = number::<usize>(4, line, &line[4..]).unwrap_err();
+
+    assert_eq!(error, ParseError::new(5, 5, "x", "a number"));
+    assert_eq!(
+        error.to_string(),
+        "line 5, column 5: expected a number, found \"x\""
+    );
+    assert_eq!(ParseError::at(0, line, "other", "x").column, 1);
+}
+
+#[test]
+fn test_end_of_input() {
+    let error = ParseError::end("a\nb\n", "a guard");
+
+    assert_eq!(error.line, 3);
+    assert_eq!(
+        error.to_string(),
+        "line 3, column 1: expected a guard, found nothing"
+    );
+}
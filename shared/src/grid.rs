@@ -0,0 +1,263 @@
+use std::{
+    collections::HashMap,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    error::ParseError,
+    map::Map,
+    vector::{Direction, Vector},
+};
+
+/// Dense row-major grid. Same coordinates as `Map`, but lookups are plain indexing.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    tiles: Vec<T>,
+    pub width: isize,
+    pub height: isize,
+}
+
+impl<T> Grid<T> {
+    /// Panics if `width` or `height` is negative.
+    pub fn new(width: isize, height: isize, tile: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        assert!(
+            width >= 0 && height >= 0,
+            "grid can not be {}x{}",
+            width,
+            height
+        );
+        Grid {
+            tiles: vec![tile; (width * height) as usize],
+            width,
+            height,
+        }
+    }
+
+    /// Builds the grid from text, one tile per char. Every line must be as wide as the first one.
+    pub fn parse(input: &str, mut parse_tile: impl FnMut(char) -> T) -> Grid<T> {
+        Grid::try_parse(input, |char| Some(parse_tile(char)), "a tile")
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Like `parse`, but `parse_tile` returns None for chars that are not `expected`.
+    /// Lines with a different width than the first one are an error too.
+    pub fn try_parse(
+        input: &str,
+        mut parse_tile: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut tiles = vec![];
+        let mut width = 0;
+        let mut height = 0;
+        for line in input.lines() {
+            let before = tiles.len();
+            for (offset, char) in line.char_indices() {
+                let tile = parse_tile(char).ok_or_else(|| {
+                    ParseError::at(
+                        height,
+                        line,
+                        &line[offset..offset + char.len_utf8()],
+                        expected,
+                    )
+                })?;
+                tiles.push(tile);
+            }
+            let line_width = tiles.len() - before;
+            if height == 0 {
+                width = line_width;
+            }
+            if line_width != width {
+                return Err(ParseError::at(
+                    height,
+                    line,
+                    line,
+                    format!("a line {} tiles wide", width),
+                ));
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            tiles,
+            width: width as isize,
+            height: height as isize,
+        })
+    }
+
+    fn index_of(&self, position: &Vector) -> Option<usize> {
+        if self.contains(position) {
+            Some((position.y * self.width + position.x) as usize)
+        } else {
+            None
+        }
+    }
+
+    fn position_of(&self, index: usize) -> Vector {
+        let index = index as isize;
+        Vector::new(index % self.width, index / self.width)
+    }
+
+    pub fn contains(&self, position: &Vector) -> bool {
+        position.x >= 0 && position.x < self.width && position.y >= 0 && position.y < self.height
+    }
+
+    pub fn get(&self, position: &Vector) -> Option<&T> {
+        self.index_of(position).map(|index| &self.tiles[index])
+    }
+
+    pub fn get_mut(&mut self, position: &Vector) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.tiles[index])
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// All positions in row-major order.
+    pub fn keys(&self) -> impl Iterator<Item = Vector> + '_ {
+        (0..self.tiles.len()).map(|index| self.position_of(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Vector, &T)> {
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(|(index, tile)| (self.position_of(index), tile))
+    }
+
+    /// Neighbouring tiles inside the grid, orthogonal ones first.
+    pub fn neighbours(
+        &self,
+        position: Vector,
+        diagonals: bool,
+    ) -> impl Iterator<Item = (Vector, &T)> {
+        let count = if diagonals { 8 } else { 4 };
+        Direction::ALL8[..count]
+            .iter()
+            .filter_map(move |direction| {
+                let neighbour = position + *direction;
+                self.get(&neighbour).map(|tile| (neighbour, tile))
+            })
+    }
+
+    pub fn to_text(&self, print_tile: impl Fn(&T) -> char) -> String {
+        let mut result = String::new();
+        for row in self.tiles.chunks(self.width.max(1) as usize) {
+            result.extend(row.iter().map(&print_tile));
+            result.push('\n');
+        }
+        result
+    }
+}
+
+impl<T> Index<Vector> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Vector) -> &Self::Output {
+        match self.index_of(&position) {
+            Some(index) => &self.tiles[index],
+            None => panic!("{:?} is outside of the grid", position),
+        }
+    }
+}
+
+impl<T> IndexMut<Vector> for Grid<T> {
+    fn index_mut(&mut self, position: Vector) -> &mut Self::Output {
+        match self.index_of(&position) {
+            Some(index) => &mut self.tiles[index],
+            None => panic!("{:?} is outside of the grid", position),
+        }
+    }
+}
+
+/// Tiles missing from the map are filled with `T::default()`.
+/// Fails with the position of a tile left of or above the origin, which a grid can not hold.
+impl<T: Default + Clone> TryFrom<Map<T>> for Grid<T> {
+    type Error = Vector;
+
+    fn try_from(map: Map<T>) -> Result<Self, Self::Error> {
+        if let Some(position) = map.keys().find(|position| position.x < 0 || position.y < 0) {
+            return Err(*position);
+        }
+        let mut grid = Grid::new(map.width, map.height, T::default());
+        for (position, tile) in HashMap::from(map) {
+            grid[position] = tile;
+        }
+        Ok(grid)
+    }
+}
+
+impl<T: Default + Clone> TryFrom<HashMap<Vector, T>> for Grid<T> {
+    type Error = Vector;
+
+    fn try_from(tiles: HashMap<Vector, T>) -> Result<Self, Self::Error> {
+        Grid::try_from(Map::from(tiles))
+    }
+}
+
+impl<T> From<Grid<T>> for Map<T> {
+    fn from(grid: Grid<T>) -> Self {
+        let mut map = Map::new();
+        let width = grid.width;
+        for (index, tile) in grid.tiles.into_iter().enumerate() {
+            let index = index as isize;
+            map.insert(Vector::new(index % width, index / width), tile);
+        }
+        map
+    }
+}
+
+impl<T> From<Grid<T>> for HashMap<Vector, T> {
+    fn from(grid: Grid<T>) -> Self {
+        HashMap::from(Map::from(grid))
+    }
+}
+
+#[test]
+fn test_index() {
+    let mut grid = Grid::parse("abc\ndef", |char| char);
+
+    assert_eq!(grid.width, 3);
+    assert_eq!(grid.height, 2);
+    assert_eq!(grid[Vector::new(2, 1)], 'f');
+    assert_eq!(grid.get(&Vector::new(3, 0)), None);
+    assert_eq!(grid.get(&Vector::new(0, -1)), None);
+
+    grid[Vector::new(0, 0)] = 'x';
+    assert_eq!(grid.to_text(|x| *x), "xbc\ndef\n");
+}
+
+#[test]
+fn test_map_conversion() {
+    let grid = Grid::parse("12\n34", |char| char.to_digit(10).unwrap());
+    let map = Map::from(grid.clone());
+
+    assert_eq!(map.get(&Vector::new(1, 1)), Some(&4));
+    assert_eq!(Grid::try_from(map), Ok(grid));
+
+    let mut map = Map::new();
+    map.insert(Vector::new(1, 0), 'a');
+    map.insert(Vector::new(0, -1), 'b');
+    assert_eq!(Grid::try_from(map), Err(Vector::new(0, -1)));
+}
+
+#[test]
+#[should_panic]
+fn test_negative_size() {
+    Grid::new(-1, 2, false);
+}
+
+#[test]
+fn test_try_parse() {
+    let error = Grid::try_parse("12\n3x", |char| char.to_digit(10), "a digit").unwrap_err();
+    assert_eq!(error, ParseError::new(2, 2, "x", "a digit"));
+
+    let error = Grid::try_parse("12\n345", |char| char.to_digit(10), "a digit").unwrap_err();
+    assert_eq!(error.expected, "a line 2 tiles wide");
+}
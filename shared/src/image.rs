@@ -0,0 +1,216 @@
+use std::{fs, io, path::Path};
+
+use crate::{
+    grid::Grid,
+    map::Map,
+    rect::Rect,
+    render::{Canvas, Colour},
+    vector::Vector,
+};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+}
+
+/// Roughly what a terminal shows for the colour, so one palette can serve both.
+impl From<Colour> for Rgb {
+    fn from(colour: Colour) -> Self {
+        match colour {
+            Colour::Red => Rgb(205, 49, 49),
+            Colour::Green => Rgb(13, 188, 121),
+            Colour::Yellow => Rgb(229, 229, 16),
+            Colour::Blue => Rgb(36, 114, 200),
+            Colour::Magenta => Rgb(188, 63, 188),
+            Colour::Cyan => Rgb(17, 168, 205),
+            Colour::White => Rgb(229, 229, 229),
+            Colour::Grey => Rgb(118, 118, 118),
+        }
+    }
+}
+
+/// RGB pixels in row-major order. Every tile is drawn as a `scale` by `scale` square.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// Draws every point of `bounds` with `palette`, `scale` pixels per tile.
+    pub fn draw(bounds: Rect, scale: usize, mut palette: impl FnMut(Vector) -> Rgb) -> Image {
+        let scale = scale.max(1);
+        let width = bounds.width.max(0) as usize * scale;
+        let height = bounds.height.max(0) as usize * scale;
+        let mut image = Image::new(width, height, Rgb::BLACK);
+        for point in bounds.points() {
+            let colour = palette(point);
+            let x = (point.x - bounds.x) as usize * scale;
+            let y = (point.y - bounds.y) as usize * scale;
+            for row in y..y + scale {
+                image.pixels[row * width + x..row * width + x + scale].fill(colour);
+            }
+        }
+        image
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, palette: impl Fn(&T) -> Rgb) -> Image {
+        let bounds = Rect::new(0, 0, grid.width, grid.height);
+        Image::draw(bounds, scale, |point| palette(&grid[point]))
+    }
+
+    /// Positions missing from the map are `background`.
+    pub fn from_map<T>(
+        map: &Map<T>,
+        scale: usize,
+        background: Rgb,
+        palette: impl Fn(&T) -> Rgb,
+    ) -> Image {
+        let bounds = Rect::new(0, 0, map.width, map.height);
+        Image::draw(bounds, scale, |point| {
+            map.get(&point).map(&palette).unwrap_or(background)
+        })
+    }
+
+    /// Points inside `bounds` are `foreground`, like the robots of day 14.
+    pub fn from_points<'a>(
+        bounds: Rect,
+        points: impl IntoIterator<Item = &'a Vector>,
+        scale: usize,
+        foreground: Rgb,
+        background: Rgb,
+    ) -> Image {
+        let mut tiles = Grid::new(bounds.width.max(0), bounds.height.max(0), false);
+        for point in points {
+            if let Some(tile) = tiles.get_mut(&Vector::new(point.x - bounds.x, point.y - bounds.y))
+            {
+                *tile = true;
+            }
+        }
+        Image::from_grid(&tiles, scale, |tile| match tile {
+            true => foreground,
+            false => background,
+        })
+    }
+
+    /// Coloured cells keep their colour. Of the rest, blanks and `.` are black and every other
+    /// char is white, which suits most puzzle maps.
+    pub fn from_canvas(canvas: &Canvas, scale: usize) -> Image {
+        Image::draw(canvas.bounds(), scale, |point| match canvas.get(point) {
+            Some(cell) => match (cell.colour, cell.char) {
+                (Some(colour), _) => Rgb::from(colour),
+                (None, ' ' | '.') => Rgb::BLACK,
+                (None, _) => Rgb::WHITE,
+            },
+            None => Rgb::BLACK,
+        })
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        match x < self.width {
+            true => self.pixels.get(y * self.width + x).copied(),
+            false => None,
+        }
+    }
+
+    /// Three bytes per pixel, the layout encoders take.
+    pub fn rgb_bytes(&self) -> Vec<u8> {
+        self.pixels.iter().flat_map(|x| [x.0, x.1, x.2]).collect()
+    }
+
+    /// Binary PPM, which most image viewers open and which needs no encoder.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend(self.rgb_bytes());
+        bytes
+    }
+
+    #[cfg(feature = "png")]
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        let mut encoder = png::Encoder::new(&mut bytes, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.rgb_bytes()))
+            .expect("writing to memory should not fail");
+        bytes
+    }
+
+    /// Writes the image in the format of the extension of `path`, `.ppm` or `.png`. PNG needs
+    /// the `png` feature of `shared`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let bytes = match path.extension().and_then(|x| x.to_str()) {
+            Some("ppm") => self.to_ppm(),
+            #[cfg(feature = "png")]
+            Some("png") => self.to_png(),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("cannot write {} as an image", path.display()),
+                ))
+            }
+        };
+        fs::write(path, bytes)
+    }
+}
+
+#[test]
+fn test_from_grid() {
+    let grid = Grid::parse("#.\n.#", |char| char == '#');
+    let image = Image::from_grid(&grid, 2, |tile| match tile {
+        true => Rgb::WHITE,
+        false => Rgb::BLACK,
+    });
+
+    assert_eq!((image.width, image.height), (4, 4));
+    assert_eq!(image.get(1, 1), Some(Rgb::WHITE));
+    assert_eq!(image.get(2, 1), Some(Rgb::BLACK));
+    assert_eq!(image.get(3, 3), Some(Rgb::WHITE));
+    assert_eq!(image.get(4, 0), None);
+}
+
+#[test]
+fn test_from_canvas() {
+    let canvas = Canvas::new(Rect::new(0, 0, 3, 1), '.')
+        .overlay([(Vector::new(1, 0), '#')])
+        .highlight([Vector::new(2, 0)], Colour::Red);
+    let image = Image::from_canvas(&canvas, 1);
+
+    assert_eq!(image.get(0, 0), Some(Rgb::BLACK));
+    assert_eq!(image.get(1, 0), Some(Rgb::WHITE));
+    assert_eq!(image.get(2, 0), Some(Rgb::from(Colour::Red)));
+}
+
+#[test]
+fn test_ppm() {
+    let points = [Vector::new(5, 5), Vector::new(7, 5), Vector::new(9, 9)];
+    let image = Image::from_points(Rect::new(5, 5, 3, 1), &points, 1, Rgb(1, 2, 3), Rgb::BLACK);
+
+    assert_eq!(
+        image.to_ppm(),
+        [b"P6\n3 1\n255\n".as_slice(), &[1, 2, 3, 0, 0, 0, 1, 2, 3]].concat()
+    );
+    assert!(image.save(Path::new("frame.gif")).is_err());
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn test_png() {
+    let image = Image::new(2, 3, Rgb::WHITE);
+
+    assert!(image.to_png().starts_with(b"\x89PNG\r\n\x1a\n"));
+}
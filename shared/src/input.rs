@@ -0,0 +1,156 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs, io, iter,
+    path::{Path, PathBuf},
+};
+
+/// Directory that replaces the workspace `inputs/` directory when set.
+pub const INPUTS_VAR: &str = "AOC_INPUTS";
+
+/// Names the real input had in some days before it was `puzzle.txt`. They are tried after
+/// it, so inputs saved under them are still found.
+const OLD_REAL_NAMES: [&str; 3] = ["real-input.txt", "puzzle-input.txt", "input.txt"];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Kind<'a> {
+    /// The personal puzzle input, `puzzle.txt`.
+    Real,
+    /// The example from the puzzle text, `example.txt`.
+    Example,
+    /// Any other file, `Named("example2")` is `example2.txt`.
+    Named(&'a str),
+}
+
+impl<'a> Kind<'a> {
+    /// Inverse of `file_name` without the extension, so "puzzle" is the real input.
+    pub fn from_name(name: &'a str) -> Kind<'a> {
+        match name {
+            "puzzle" => Kind::Real,
+            "example" => Kind::Example,
+            _ => Kind::Named(name),
+        }
+    }
+
+    pub fn file_name(&self) -> String {
+        match self {
+            Kind::Real => "puzzle.txt".to_string(),
+            Kind::Example => "example.txt".to_string(),
+            Kind::Named(name) => format!("{}.txt", name),
+        }
+    }
+
+    /// `file_name` first, then any older names of the same input.
+    fn file_names(&self) -> Vec<String> {
+        let old: &[&str] = match self {
+            Kind::Real => &OLD_REAL_NAMES,
+            _ => &[],
+        };
+        iter::once(self.file_name())
+            .chain(old.iter().map(|x| x.to_string()))
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        day: usize,
+        file: String,
+        tried: Vec<PathBuf>,
+    },
+    Unreadable {
+        path: PathBuf,
+        error: io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { day, file, tried } => {
+                write!(f, "no {} for day {}, tried:", file, day)?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                write!(f, "\nsave it as {} in one of these directories", file)
+            }
+            InputError::Unreadable { path, error } => {
+                write!(f, "cannot read {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("shared should be inside the workspace")
+}
+
+/// Places to look in order: `$AOC_INPUTS/day-N`, `inputs/day-N` in the workspace and
+/// finally `day-N/src` in the workspace. The workspace is the parent of `shared`, whose
+/// `CARGO_MANIFEST_DIR` is compiled in, so this never depends on the calling crate.
+fn candidates(day: usize, file: &str, override_dir: Option<PathBuf>) -> Vec<PathBuf> {
+    let day_dir = format!("day-{}", day);
+    let workspace = workspace_dir();
+    override_dir
+        .into_iter()
+        .chain([workspace.join("inputs")])
+        .map(|x| x.join(&day_dir).join(file))
+        .chain([workspace.join(&day_dir).join("src").join(file)])
+        .collect()
+}
+
+/// Finds the input without depending on the working directory.
+pub fn locate(day: usize, kind: Kind) -> Result<PathBuf, InputError> {
+    let file = kind.file_name();
+    let override_dir = env::var_os(INPUTS_VAR).map(PathBuf::from);
+    let tried: Vec<PathBuf> = kind
+        .file_names()
+        .iter()
+        .flat_map(|name| candidates(day, name, override_dir.clone()))
+        .collect();
+    match tried.iter().find(|x| x.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(InputError::NotFound { day, file, tried }),
+    }
+}
+
+pub fn read(day: usize, kind: Kind) -> Result<String, InputError> {
+    let path = locate(day, kind)?;
+    fs::read_to_string(&path).map_err(|error| InputError::Unreadable { path, error })
+}
+
+#[test]
+fn test_candidates() {
+    let workspace = workspace_dir();
+    let tried = candidates(7, "example.txt", Some(PathBuf::from("/tmp/inputs")));
+
+    assert_eq!(
+        tried,
+        vec![
+            PathBuf::from("/tmp/inputs/day-7/example.txt"),
+            workspace.join("inputs/day-7/example.txt"),
+            workspace.join("day-7/src/example.txt"),
+        ]
+    );
+    assert_eq!(candidates(7, "example.txt", None).len(), 2);
+}
+
+#[test]
+fn test_missing_input() {
+    let error = locate(7, Kind::Named("does-not-exist")).unwrap_err();
+    let message = error.to_string();
+
+    assert!(message.starts_with("no does-not-exist.txt for day 7, tried:"));
+    assert!(message.contains("day-7/src/does-not-exist.txt"));
+    assert!(message.ends_with("save it as does-not-exist.txt in one of these directories"));
+
+    let message = locate(99, Kind::Real).unwrap_err().to_string();
+    assert!(message.contains("day-99/src/puzzle.txt"));
+    assert!(message.contains("day-99/src/real-input.txt"));
+    assert!(message.ends_with("save it as puzzle.txt in one of these directories"));
+}
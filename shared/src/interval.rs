@@ -0,0 +1,394 @@
+use std::{
+    cmp::{max, min},
+    ops::Range,
+};
+
+use crate::num::Integer;
+
+/// Half-open range of integers. `start` is inclusive, `end` is not.
+#[derive(Default, PartialEq, Eq, Debug, Copy, Clone, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    pub const fn new(start: T, end: T) -> Interval<T> {
+        Interval { start, end }
+    }
+
+    /// Number of values, 0 when `end` is not after `start`.
+    pub fn len(&self) -> T {
+        match self.is_empty() {
+            true => T::ZERO,
+            false => self.end - self.start,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let interval = Interval::new(max(self.start, other.start), min(self.end, other.end));
+        (!interval.is_empty()).then_some(interval)
+    }
+}
+
+impl<T> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Interval {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+type Tree<T> = Option<Box<Node<T>>>;
+
+/// Treap node, ordered by position and heap ordered by `priority`.
+#[derive(Debug, Clone)]
+struct Node<T> {
+    interval: Interval<T>,
+    priority: u64,
+    /// Intervals in this subtree.
+    count: usize,
+    /// Length of the longest interval in this subtree, so first fit can skip whole subtrees.
+    longest: T,
+    left: Tree<T>,
+    right: Tree<T>,
+}
+
+impl<T: Integer> Node<T> {
+    fn update(&mut self) {
+        self.count = 1;
+        self.longest = self.interval.len();
+        for child in [&self.left, &self.right].into_iter().flatten() {
+            self.count += child.count;
+            self.longest = max(self.longest, child.longest);
+        }
+    }
+}
+
+/// Splits into the intervals for which `before` holds and the rest. `before` has to hold for a
+/// prefix of the intervals.
+fn split<T: Integer>(tree: Tree<T>, before: &impl Fn(&Interval<T>) -> bool) -> (Tree<T>, Tree<T>) {
+    let Some(mut node) = tree else {
+        return (None, None);
+    };
+    match before(&node.interval) {
+        true => {
+            let (middle, right) = split(node.right.take(), before);
+            node.right = middle;
+            node.update();
+            (Some(node), right)
+        }
+        false => {
+            let (left, middle) = split(node.left.take(), before);
+            node.left = middle;
+            node.update();
+            (left, Some(node))
+        }
+    }
+}
+
+/// Joins two trees when every interval of `left` comes before every interval of `right`.
+fn merge<T: Integer>(left: Tree<T>, right: Tree<T>) -> Tree<T> {
+    match (left, right) {
+        (None, tree) | (tree, None) => tree,
+        (Some(mut left), Some(mut right)) => match left.priority > right.priority {
+            true => {
+                left.right = merge(left.right.take(), Some(right));
+                left.update();
+                Some(left)
+            }
+            false => {
+                right.left = merge(Some(left), right.left.take());
+                right.update();
+                Some(right)
+            }
+        },
+    }
+}
+
+fn first<T: Copy>(tree: &Tree<T>) -> Option<Interval<T>> {
+    let mut node = tree.as_deref()?;
+    while let Some(left) = node.left.as_deref() {
+        node = left;
+    }
+    Some(node.interval)
+}
+
+fn last<T: Copy>(tree: &Tree<T>) -> Option<Interval<T>> {
+    let mut node = tree.as_deref()?;
+    while let Some(right) = node.right.as_deref() {
+        node = right;
+    }
+    Some(node.interval)
+}
+
+/// Set of integers stored as sorted intervals. Overlapping and touching intervals are merged,
+/// so `[0, 2)` and `[2, 5)` become `[0, 5)`. Inserting, removing and searching take logarithmic
+/// time, apart from dropping the intervals an insert swallows.
+#[derive(Debug, Clone)]
+pub struct IntervalSet<T> {
+    root: Tree<T>,
+    seed: u64,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet {
+            root: None,
+            seed: 0x9e37_79b9_7f4a_7c15,
+        }
+    }
+
+    /// Number of separate intervals.
+    pub fn len(&self) -> usize {
+        self.root.as_ref().map_or(0, |x| x.count)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    // Xorshift, the treap only needs priorities that look random, not unpredictable ones
+    fn node(&mut self, interval: Interval<T>) -> Tree<T> {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        Some(Box::new(Node {
+            interval,
+            priority: self.seed,
+            count: 1,
+            longest: interval.len(),
+            left: None,
+            right: None,
+        }))
+    }
+
+    /// Adds every value of `interval`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let (before, rest) = split(self.root.take(), &|x| x.end < interval.start);
+        let (touching, after) = split(rest, &|x| x.start <= interval.end);
+        let start = first(&touching).map_or(interval.start, |x| min(x.start, interval.start));
+        let end = last(&touching).map_or(interval.end, |x| max(x.end, interval.end));
+        let node = self.node(Interval::new(start, end));
+        self.root = merge(merge(before, node), after);
+    }
+
+    /// Takes every value of `interval` out, splitting the intervals it cuts through.
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let (before, rest) = split(self.root.take(), &|x| x.end <= interval.start);
+        let (cut, after) = split(rest, &|x| x.start < interval.end);
+        let left = first(&cut)
+            .filter(|x| x.start < interval.start)
+            .and_then(|x| self.node(Interval::new(x.start, interval.start)));
+        let right = last(&cut)
+            .filter(|x| x.end > interval.end)
+            .and_then(|x| self.node(Interval::new(interval.end, x.end)));
+        self.root = merge(merge(before, left), merge(right, after));
+    }
+
+    /// The interval that contains `value`.
+    pub fn get(&self, value: T) -> Option<Interval<T>> {
+        let mut tree = &self.root;
+        while let Some(node) = tree {
+            if value < node.interval.start {
+                tree = &node.left;
+            } else if value >= node.interval.end {
+                tree = &node.right;
+            } else {
+                return Some(node.interval);
+            }
+        }
+        None
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.get(value).is_some()
+    }
+
+    /// The first `size` values of the leftmost interval that has room for them, like an
+    /// allocator looking for free space. The set is not changed.
+    pub fn first_fit(&self, size: T) -> Option<Interval<T>> {
+        let mut node = self.root.as_deref().filter(|x| x.longest >= size)?;
+        loop {
+            if let Some(left) = node.left.as_deref().filter(|x| x.longest >= size) {
+                node = left;
+            } else if node.interval.len() >= size {
+                let start = node.interval.start;
+                return Some(Interval::new(start, start + size));
+            } else {
+                node = node.right.as_deref()?;
+            }
+        }
+    }
+
+    /// Intervals in order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: vec![] };
+        iter.push_left(&self.root);
+        iter
+    }
+
+    /// Values in both sets.
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.difference(&self.difference(other))
+    }
+
+    /// Values in this set that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for interval in other.iter() {
+            result.remove(interval);
+        }
+        result
+    }
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Integer> PartialEq for IntervalSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Integer> Eq for IntervalSet<T> {}
+
+impl<T: Integer> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, mut tree: &'a Tree<T>) {
+        while let Some(node) = tree {
+            self.stack.push(node);
+            tree = &node.left;
+        }
+    }
+}
+
+impl<T: Copy> Iterator for Iter<'_, T> {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        Some(node.interval)
+    }
+}
+
+#[cfg(test)]
+fn intervals(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+    set.iter().map(|x| (x.start, x.end)).collect()
+}
+
+#[test]
+fn test_interval() {
+    let interval = Interval::new(2, 5);
+
+    assert_eq!(interval.len(), 3);
+    assert!(interval.contains(2) && !interval.contains(5));
+    assert_eq!(
+        interval.intersection(&Interval::from(4..9)),
+        Some(Interval::new(4, 5))
+    );
+    assert_eq!(interval.intersection(&Interval::new(5, 9)), None);
+    assert_eq!(Interval::new(5, 2).len(), 0);
+}
+
+#[test]
+fn test_insert_and_remove() {
+    let mut set: IntervalSet<i32> = [0..2, 5..7, 2..3, 10..12, 6..10]
+        .into_iter()
+        .map(Interval::from)
+        .collect();
+    assert_eq!(intervals(&set), vec![(0, 3), (5, 12)]);
+
+    set.remove(Interval::new(-5, 1));
+    set.remove(Interval::new(7, 9));
+    assert_eq!(intervals(&set), vec![(1, 3), (5, 7), (9, 12)]);
+    assert_eq!(set.get(6), Some(Interval::new(5, 7)));
+    assert!(!set.contains(8));
+
+    set.insert(Interval::new(0, 100));
+    assert_eq!(intervals(&set), vec![(0, 100)]);
+    set.remove(Interval::new(0, 100));
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_first_fit() {
+    let set: IntervalSet<i32> = [0..1, 3..5, 8..11, 20..30]
+        .into_iter()
+        .map(Interval::from)
+        .collect();
+
+    assert_eq!(set.first_fit(1), Some(Interval::new(0, 1)));
+    assert_eq!(set.first_fit(2), Some(Interval::new(3, 5)));
+    assert_eq!(set.first_fit(3), Some(Interval::new(8, 11)));
+    assert_eq!(set.first_fit(4), Some(Interval::new(20, 24)));
+    assert_eq!(set.first_fit(11), None);
+}
+
+#[test]
+fn test_set_operations() {
+    let a: IntervalSet<i32> = [0..10, 20..30].into_iter().map(Interval::from).collect();
+    let b: IntervalSet<i32> = [Interval::new(5, 25)].into_iter().collect();
+
+    assert_eq!(intervals(&a.intersection(&b)), vec![(5, 10), (20, 25)]);
+    assert_eq!(intervals(&a.difference(&b)), vec![(0, 5), (25, 30)]);
+    assert_eq!(intervals(&b.difference(&a)), vec![(10, 20)]);
+    assert_eq!(a.intersection(&IntervalSet::new()), IntervalSet::new());
+}
+
+#[test]
+fn test_many_intervals() {
+    // Every other value, then filling the gaps from the right
+    let mut set: IntervalSet<i64> = (0..10_000)
+        .map(|x| Interval::new(x * 2, x * 2 + 1))
+        .collect();
+    assert_eq!(set.len(), 10_000);
+    assert_eq!(set.first_fit(2), None);
+
+    for x in (0..9_999).rev() {
+        set.insert(Interval::new(x * 2 + 1, x * 2 + 2));
+    }
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        vec![Interval::new(0, 19_999)]
+    );
+}
@@ -1,2 +1,22 @@
+pub mod anim;
+pub mod components;
+pub mod cycle;
+pub mod error;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod interval;
+pub mod linear;
 pub mod map;
+pub mod math;
+pub mod num;
+pub mod parse;
+pub mod point;
+pub mod rational;
+pub mod rect;
+pub mod region;
+pub mod render;
+pub mod search;
+pub mod sim;
+pub mod solution;
 pub mod vector;
@@ -0,0 +1,162 @@
+use std::fmt::{self, Display};
+
+use crate::rational::Rational;
+
+/// Every solution of a linear system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solutions {
+    Unique(Vec<Rational>),
+    /// The equations contradict each other.
+    Inconsistent,
+    /// Every `particular + t1 * directions[0] + t2 * directions[1] + ...` is a solution, one
+    /// direction for each free variable.
+    Infinite {
+        particular: Vec<Rational>,
+        directions: Vec<Vec<Rational>>,
+    },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LinearError {
+    /// A row of the matrix has another length than the first one, or there are more or fewer
+    /// right hand sides than rows.
+    Shape,
+    Overflow,
+}
+
+impl Display for LinearError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinearError::Shape => write!(f, "the matrix and right hand side do not match"),
+            LinearError::Overflow => write!(f, "an intermediate value does not fit in an i128"),
+        }
+    }
+}
+
+impl std::error::Error for LinearError {}
+
+/// Solves `matrix * x = rhs` exactly with Gaussian elimination. Meant for the small systems
+/// of a puzzle, like two buttons and a prize.
+pub fn solve<T: Copy + Into<Rational>>(
+    matrix: &[Vec<T>],
+    rhs: &[T],
+) -> Result<Solutions, LinearError> {
+    let columns = matrix.first().map_or(0, Vec::len);
+    if matrix.len() != rhs.len() || matrix.iter().any(|row| row.len() != columns) {
+        return Err(LinearError::Shape);
+    }
+    let mut rows: Vec<Vec<Rational>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, value)| row.iter().chain([value]).map(|x| (*x).into()).collect())
+        .collect();
+
+    // Reduced row echelon form, `pivots[i]` is the column of the leading 1 in row i
+    let mut pivots = vec![];
+    for column in 0..columns {
+        let Some(found) = (pivots.len()..rows.len()).find(|&row| !rows[row][column].is_zero())
+        else {
+            continue;
+        };
+        let row = pivots.len();
+        rows.swap(row, found);
+
+        let pivot = rows[row][column];
+        for value in rows[row].iter_mut() {
+            *value = value.checked_div(pivot).ok_or(LinearError::Overflow)?;
+        }
+        let pivot_row = rows[row].clone();
+        for (other, values) in rows.iter_mut().enumerate() {
+            let factor = values[column];
+            if other == row || factor.is_zero() {
+                continue;
+            }
+            for (value, pivot_value) in values.iter_mut().zip(&pivot_row).skip(column) {
+                let change = factor
+                    .checked_mul(*pivot_value)
+                    .ok_or(LinearError::Overflow)?;
+                *value = value.checked_sub(change).ok_or(LinearError::Overflow)?;
+            }
+        }
+        pivots.push(column);
+    }
+
+    if rows[pivots.len()..]
+        .iter()
+        .any(|row| !row[columns].is_zero())
+    {
+        return Ok(Solutions::Inconsistent);
+    }
+
+    let mut particular = vec![Rational::ZERO; columns];
+    for (row, &column) in pivots.iter().enumerate() {
+        particular[column] = rows[row][columns];
+    }
+    if pivots.len() == columns {
+        return Ok(Solutions::Unique(particular));
+    }
+
+    let mut directions = vec![];
+    for free in (0..columns).filter(|x| !pivots.contains(x)) {
+        let mut direction = vec![Rational::ZERO; columns];
+        direction[free] = Rational::ONE;
+        for (row, &column) in pivots.iter().enumerate() {
+            direction[column] = rows[row][free].checked_neg().ok_or(LinearError::Overflow)?;
+        }
+        directions.push(direction);
+    }
+    Ok(Solutions::Infinite {
+        particular,
+        directions,
+    })
+}
+
+#[cfg(test)]
+fn rationals(values: &[(i128, i128)]) -> Vec<Rational> {
+    values.iter().map(|&(n, d)| Rational::new(n, d)).collect()
+}
+
+#[test]
+fn test_unique() {
+    let solutions = solve(&[vec![94, 22], vec![34, 67]], &[8400, 5400]);
+    assert_eq!(
+        solutions,
+        Ok(Solutions::Unique(rationals(&[(80, 1), (40, 1)])))
+    );
+
+    let solutions = solve(&[vec![0, 2], vec![3, 0]], &[1, 1]);
+    assert_eq!(
+        solutions,
+        Ok(Solutions::Unique(rationals(&[(1, 3), (1, 2)])))
+    );
+}
+
+#[test]
+fn test_inconsistent_and_infinite() {
+    assert_eq!(
+        solve(&[vec![1, 2], vec![2, 4]], &[3, 7]),
+        Ok(Solutions::Inconsistent)
+    );
+    assert_eq!(
+        solve(&[vec![1, 2], vec![2, 4]], &[3, 6]),
+        Ok(Solutions::Infinite {
+            particular: rationals(&[(3, 1), (0, 1)]),
+            directions: vec![rationals(&[(-2, 1), (1, 1)])],
+        })
+    );
+    assert_eq!(
+        solve(&[vec![1, 0, 0]], &[5]),
+        Ok(Solutions::Infinite {
+            particular: rationals(&[(5, 1), (0, 1), (0, 1)]),
+            directions: vec![
+                rationals(&[(0, 1), (1, 1), (0, 1)]),
+                rationals(&[(0, 1), (0, 1), (1, 1)])
+            ],
+        })
+    );
+    assert_eq!(solve(&[vec![1, 2]], &[1, 2]), Err(LinearError::Shape));
+    assert_eq!(
+        solve(&[vec![i128::MAX, 1], vec![1, i128::MAX]], &[1, 1]),
+        Err(LinearError::Overflow)
+    );
+}
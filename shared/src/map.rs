@@ -1,7 +1,162 @@
 use std::collections::HashMap;
 
-use crate::vector::Vector;
+use crate::{
+    error::ParseError,
+    vector::{Direction, Vector},
+};
 
-struct Map<TTile> {
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Map<TTile> {
     tiles: HashMap<Vector, TTile>,
+    pub width: isize,
+    pub height: isize,
+}
+
+impl<TTile> Map<TTile> {
+    pub fn new() -> Map<TTile> {
+        Map {
+            tiles: HashMap::new(),
+            width: 0,
+            height: 0,
+        }
+    }
+
+    /// Builds the map from text, one tile per char. `x` grows to the right and `y` grows downwards.
+    pub fn parse(input: &str, mut parse_tile: impl FnMut(char) -> TTile) -> Map<TTile> {
+        Map::try_parse(input, |char| Some(parse_tile(char)), "a tile")
+            .expect("every char should be a tile")
+    }
+
+    /// Like `parse`, but `parse_tile` returns None for chars that are not `expected`.
+    pub fn try_parse(
+        input: &str,
+        mut parse_tile: impl FnMut(char) -> Option<TTile>,
+        expected: &str,
+    ) -> Result<Map<TTile>, ParseError> {
+        let mut map = Map::new();
+        for (y, line) in input.lines().enumerate() {
+            for (x, (offset, char)) in line.char_indices().enumerate() {
+                let tile = parse_tile(char).ok_or_else(|| {
+                    ParseError::at(y, line, &line[offset..offset + char.len_utf8()], expected)
+                })?;
+                map.insert(Vector::new(x as isize, y as isize), tile);
+            }
+        }
+        Ok(map)
+    }
+
+    /// Inserts a tile, growing width and height if the position is outside the current map.
+    pub fn insert(&mut self, position: Vector, tile: TTile) -> Option<TTile> {
+        self.width = self.width.max(position.x + 1);
+        self.height = self.height.max(position.y + 1);
+        self.tiles.insert(position, tile)
+    }
+
+    pub fn get(&self, position: &Vector) -> Option<&TTile> {
+        self.tiles.get(position)
+    }
+
+    pub fn get_mut(&mut self, position: &Vector) -> Option<&mut TTile> {
+        self.tiles.get_mut(position)
+    }
+
+    pub fn contains(&self, position: &Vector) -> bool {
+        self.tiles.contains_key(position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &Vector> {
+        self.tiles.keys()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Vector, &TTile)> {
+        self.tiles.iter()
+    }
+
+    /// Neighbouring tiles that exist on the map, orthogonal ones first.
+    pub fn neighbours(
+        &self,
+        position: Vector,
+        diagonals: bool,
+    ) -> impl Iterator<Item = (Vector, &TTile)> {
+        let count = if diagonals { 8 } else { 4 };
+        Direction::ALL8[..count]
+            .iter()
+            .filter_map(move |direction| {
+                let neighbour = position + *direction;
+                self.get(&neighbour).map(|tile| (neighbour, tile))
+            })
+    }
+
+    /// Prints the map back to text. Missing tiles are printed as spaces.
+    pub fn to_text(&self, print_tile: impl Fn(&TTile) -> char) -> String {
+        let mut result = String::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                match self.get(&Vector::new(x, y)) {
+                    Some(tile) => result.push(print_tile(tile)),
+                    None => result.push(' '),
+                }
+            }
+            result.push('\n');
+        }
+        result
+    }
+}
+
+impl<TTile> Default for Map<TTile> {
+    fn default() -> Self {
+        Map::new()
+    }
+}
+
+impl<TTile> From<HashMap<Vector, TTile>> for Map<TTile> {
+    fn from(tiles: HashMap<Vector, TTile>) -> Self {
+        let mut map = Map::new();
+        for (position, tile) in tiles {
+            map.insert(position, tile);
+        }
+        map
+    }
+}
+
+impl<TTile> From<Map<TTile>> for HashMap<Vector, TTile> {
+    fn from(map: Map<TTile>) -> Self {
+        map.tiles
+    }
+}
+
+#[test]
+fn test_parse_and_print() {
+    let input = "#..\n.#.\n..#\n";
+    let map = Map::parse(input, |char| char == '#');
+
+    assert_eq!(map.width, 3);
+    assert_eq!(map.height, 3);
+    assert_eq!(map.get(&Vector::new(1, 1)), Some(&true));
+    assert_eq!(map.get(&Vector::new(1, 0)), Some(&false));
+    assert!(!map.contains(&Vector::new(3, 0)));
+
+    assert_eq!(map.to_text(|tile| if *tile { '#' } else { '.' }), input);
+}
+
+#[test]
+fn test_neighbours() {
+    let map = Map::parse("abc\ndef\nghi", |char| char);
+
+    let corner: Vec<char> = map
+        .neighbours(Vector::new(0, 0), false)
+        .map(|x| *x.1)
+        .collect();
+    assert_eq!(corner, vec!['b', 'd']);
+
+    let centre = map.neighbours(Vector::new(1, 1), true).count();
+    assert_eq!(centre, 8);
 }
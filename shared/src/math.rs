@@ -0,0 +1,273 @@
+use std::fmt::{self, Display};
+
+use crate::num::{Integer, Signed};
+
+fn two<T: Integer>() -> T {
+    T::ONE + T::ONE
+}
+
+/// `a` modulo `m`, in `0..|m|` even when `a` is negative.
+pub fn modulo<T: Integer>(a: T, m: T) -> T {
+    let r = a % m;
+    match r < T::ZERO {
+        true if m < T::ZERO => r - m,
+        true => r + m,
+        false => r,
+    }
+}
+
+/// Greatest common divisor, never negative. Panics when the result does not fit, like
+/// `gcd(i32::MIN, 0)`.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b).expect("gcd should fit in the type")
+}
+
+pub fn checked_gcd<T: Integer>(mut a: T, mut b: T) -> Option<T> {
+    while b != T::ZERO {
+        // Only `MIN % -1` overflows, and that remainder is 0
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+    match a < T::ZERO {
+        true => T::ZERO.checked_sub(a),
+        false => Some(a),
+    }
+}
+
+/// Least common multiple, never negative. `lcm(0, x)` is 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect("lcm should fit in the type")
+}
+
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    let lcm = (a / checked_gcd(a, b)?).checked_mul(b)?;
+    match lcm < T::ZERO {
+        true => T::ZERO.checked_sub(lcm),
+        false => Some(lcm),
+    }
+}
+
+/// `(g, x, y)` with `a * x + b * y == g`, where `g` is the gcd of `a` and `b`. The sign of
+/// `g` follows the inputs, so `g` is only positive when `a` and `b` are not negative.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    (old_r, old_x, old_y)
+}
+
+/// `x` in `0..m` with `a * x ≡ 1 (mod m)`, None when `a` and `m` are not coprime.
+pub fn mod_inverse<T: Signed>(a: T, m: T) -> Option<T> {
+    if m <= T::ZERO {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(modulo(a, m), m);
+    (g == T::ONE).then(|| modulo(x, m))
+}
+
+/// `a * b` modulo `m`. When the product does not fit it is built by doubling, which only
+/// needs `2 * m` to fit. None when that does not fit either or `m` is not positive.
+pub fn checked_mul_mod<T: Integer>(a: T, b: T, m: T) -> Option<T> {
+    if m <= T::ZERO {
+        return None;
+    }
+    let (mut a, mut b) = (modulo(a, m), modulo(b, m));
+    if let Some(product) = a.checked_mul(b) {
+        return Some(product % m);
+    }
+    let mut result = T::ZERO;
+    while b > T::ZERO {
+        if b % two() == T::ONE {
+            result = result.checked_add(a)? % m;
+        }
+        a = a.checked_add(a)? % m;
+        b = b / two();
+    }
+    Some(result)
+}
+
+/// `base` to the power `exp`, modulo `m`. Panics when a product overflows, which needs `m`
+/// above half of the largest value of the type.
+pub fn mod_pow<T: Integer>(base: T, exp: T, m: T) -> T {
+    checked_mod_pow(base, exp, m).expect("mod_pow should fit in the type")
+}
+
+/// None when `m` is not positive, `exp` is negative or a product overflows.
+pub fn checked_mod_pow<T: Integer>(base: T, mut exp: T, m: T) -> Option<T> {
+    if m <= T::ZERO || exp < T::ZERO {
+        return None;
+    }
+    let mut result = modulo(T::ONE, m);
+    let mut base = modulo(base, m);
+    while exp > T::ZERO {
+        if exp % two() == T::ONE {
+            result = checked_mul_mod(result, base, m)?;
+        }
+        exp = exp / two();
+        if exp > T::ZERO {
+            base = checked_mul_mod(base, base, m)?;
+        }
+    }
+    Some(result)
+}
+
+/// Largest `r` with `r * r <= n`. Panics when `n` is negative.
+pub fn isqrt<T: Integer>(n: T) -> T {
+    checked_isqrt(n).expect("isqrt needs a number that is not negative")
+}
+
+pub fn checked_isqrt<T: Integer>(n: T) -> Option<T> {
+    if n < T::ZERO {
+        return None;
+    }
+    if n < two() {
+        return Some(n);
+    }
+    // Newton's method from above, starting at n / 2 keeps `x + n / x` from overflowing
+    let mut x = n / two();
+    loop {
+        let next = (x + n / x) / two();
+        if next >= x {
+            return Some(x);
+        }
+        x = next;
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CrtError {
+    /// Congruence with this index contradicts the ones before it.
+    Inconsistent(usize),
+    /// Modulus with this index is not positive.
+    InvalidModulus(usize),
+    /// The combined modulus does not fit in the type.
+    Overflow,
+}
+
+impl Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::Inconsistent(index) => {
+                write!(f, "congruence {} contradicts the ones before it", index + 1)
+            }
+            CrtError::InvalidModulus(index) => {
+                write!(
+                    f,
+                    "congruence {} has a modulus that is not positive",
+                    index + 1
+                )
+            }
+            CrtError::Overflow => write!(f, "the combined modulus does not fit"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// Solves `x ≡ a (mod m)` for every `(a, m)` in `congruences`. The moduli do not have to be
+/// coprime. Returns the smallest `x` that is not negative and the combined modulus, the lcm of
+/// all moduli, so every solution is `x + k * modulus`.
+pub fn crt<T: Signed>(congruences: &[(T, T)]) -> Result<(T, T), CrtError> {
+    let mut x = T::ZERO;
+    let mut modulus = T::ONE;
+    for (index, &(a, m)) in congruences.iter().enumerate() {
+        if m <= T::ZERO {
+            return Err(CrtError::InvalidModulus(index));
+        }
+        let a = modulo(a, m);
+        let (g, p, _) = extended_gcd(modulus, m);
+        let difference = a - modulo(x, m);
+        if difference % g != T::ZERO {
+            return Err(CrtError::Inconsistent(index));
+        }
+
+        // x + modulus * k ≡ a (mod m) gives k ≡ difference / g * p (mod m / g)
+        let step = m / g;
+        let k = checked_mul_mod(difference / g, p, step).ok_or(CrtError::Overflow)?;
+        let combined = modulus.checked_mul(step).ok_or(CrtError::Overflow)?;
+        x = modulus
+            .checked_mul(k)
+            .and_then(|x_step| x.checked_add(x_step))
+            .ok_or(CrtError::Overflow)?;
+        x = modulo(x, combined);
+        modulus = combined;
+    }
+    Ok((x, modulus))
+}
+
+#[test]
+fn test_gcd_lcm() {
+    assert_eq!(gcd(12u8, 18), 6);
+    assert_eq!(gcd(-12i64, 18), 6);
+    assert_eq!(gcd(0, 7), 7);
+    assert_eq!(lcm(4i32, -6), 12);
+    assert_eq!(lcm(0u32, 6), 0);
+    assert_eq!(checked_gcd(i32::MIN, -1), Some(1));
+    assert_eq!(checked_gcd(-1, i32::MIN), Some(1));
+    assert_eq!(checked_lcm(i32::MIN, -1), None);
+    assert_eq!(checked_gcd(i32::MIN, 0), None);
+    assert_eq!(checked_lcm(u8::MAX, 254), None);
+    assert_eq!(lcm(101i128, 103), 10403);
+}
+
+#[test]
+fn test_extended_gcd() {
+    let (g, x, y) = extended_gcd(240i32, 46);
+
+    assert_eq!(g, 2);
+    assert_eq!(240 * x + 46 * y, 2);
+    assert_eq!(mod_inverse(3i64, 11), Some(4));
+    assert_eq!(mod_inverse(-3i64, 11), Some(7));
+    assert_eq!(mod_inverse(6i64, 9), None);
+}
+
+#[test]
+fn test_mod_pow() {
+    assert_eq!(mod_pow(4u64, 13, 497), 445);
+    assert_eq!(mod_pow(-2i32, 3, 5), 2);
+    assert_eq!(mod_pow(7u8, 0, 1), 0);
+    assert_eq!(mod_pow(2u8, 7, 120), 8);
+    assert_eq!(checked_mod_pow(2u8, 7, 200), Some(128));
+    assert_eq!(checked_mod_pow(150u8, 2, 200), None);
+    // Fermat's little theorem, with products far larger than u64
+    assert_eq!(
+        mod_pow(3u64, 1_000_000_000_000_000_002, 1_000_000_000_000_000_003),
+        1
+    );
+}
+
+#[test]
+fn test_isqrt() {
+    let roots: Vec<u32> = (0..10u32).map(isqrt).collect();
+
+    assert_eq!(roots, vec![0, 1, 1, 1, 2, 2, 2, 2, 2, 3]);
+    assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    assert_eq!(isqrt(i128::MAX), 13043817825332782212);
+    assert_eq!(checked_isqrt(-1i8), None);
+}
+
+#[test]
+fn test_crt() {
+    assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+    assert_eq!(crt(&[(3i32, 4), (1, 6)]), Ok((7, 12)));
+    assert_eq!(crt(&[(3i32, 4), (2, 6)]), Err(CrtError::Inconsistent(1)));
+    assert_eq!(crt(&[(1i32, 0)]), Err(CrtError::InvalidModulus(0)));
+    assert_eq!(crt(&[(1i8, 11), (2, 13)]), Err(CrtError::Overflow));
+    assert_eq!(
+        crt(&[(1i64, 1_000_000_007), (2, 998_244_353)]).map(|x| x.1),
+        Ok(998_244_359_987_710_471)
+    );
+    assert_eq!(crt::<i32>(&[]), Ok((0, 1)));
+    assert_eq!(
+        CrtError::Inconsistent(1).to_string(),
+        "congruence 2 contradicts the ones before it"
+    );
+}
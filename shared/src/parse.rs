@@ -0,0 +1,260 @@
+use std::{iter, str::FromStr};
+
+use crate::error::ParseError;
+
+/// Slices of `line` that look like integers: a run of digits with an optional '-' in front.
+/// Everything else, whitespace included, only separates them.
+pub fn int_tokens(line: &str) -> impl Iterator<Item = &str> + '_ {
+    let bytes = line.as_bytes();
+    let mut position = 0;
+    iter::from_fn(move || {
+        while position < bytes.len() {
+            let start = position;
+            let negative =
+                bytes[position] == b'-' && bytes.get(position + 1).is_some_and(u8::is_ascii_digit);
+            position += 1;
+            if negative || bytes[start].is_ascii_digit() {
+                while bytes.get(position).is_some_and(u8::is_ascii_digit) {
+                    position += 1;
+                }
+                return Some(&line[start..position]);
+            }
+        }
+        None
+    })
+}
+
+/// Every integer in `line`. Numbers that do not fit in `T` are skipped, negative ones included
+/// when `T` is unsigned.
+pub fn ints<T: FromStr>(line: &str) -> impl Iterator<Item = T> + '_ {
+    int_tokens(line).filter_map(|token| token.parse().ok())
+}
+
+/// Exactly `N` integers, or None if `line` has more or fewer or one does not fit in `T`.
+pub fn ints_n<T: FromStr + Default + Copy, const N: usize>(line: &str) -> Option<[T; N]> {
+    let mut tokens = int_tokens(line);
+    let mut result = [T::default(); N];
+    for slot in result.iter_mut() {
+        *slot = tokens.next()?.parse().ok()?;
+    }
+    match tokens.next() {
+        Some(_) => None,
+        None => Some(result),
+    }
+}
+
+/// Value of every decimal digit in `line`, other chars are skipped.
+pub fn digits(line: &str) -> impl Iterator<Item = u32> + '_ {
+    line.chars().filter_map(|char| char.to_digit(10))
+}
+
+/// One line of the input, `index` counts from 0 across the whole input.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Error pointing at `part`, which should be a slice of this line.
+    pub fn error(&self, part: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at(self.index, self.text, part, expected)
+    }
+
+    /// Exactly `N` integers, `expected` describes the line when there are not.
+    pub fn ints_n<T: FromStr + Default + Copy, const N: usize>(
+        &self,
+        expected: &str,
+    ) -> Result<[T; N], ParseError> {
+        ints_n(self.text).ok_or_else(|| self.error(self.text, expected))
+    }
+}
+
+/// Lines between two blank lines.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// Position among all sections, from 0.
+    pub number: usize,
+    /// Index of the first line in the whole input.
+    pub start: usize,
+    /// The lines of the section, without the blank lines around it.
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> impl Iterator<Item = Line<'a>> + 'a {
+        let start = self.start;
+        self.text
+            .lines()
+            .enumerate()
+            .map(move |(index, text)| Line {
+                index: start + index,
+                text,
+            })
+    }
+
+    /// The lines as a fixed size record.
+    pub fn lines_n<const N: usize>(&self) -> Result<[Line<'a>; N], ParseError> {
+        let mut lines = self.lines();
+        let mut result = [Line::default(); N];
+        for (count, slot) in result.iter_mut().enumerate() {
+            *slot = lines.next().ok_or_else(|| {
+                let expected = format!("line {} of {}", count + 1, N);
+                ParseError::new(self.start + count + 1, 1, "", expected).in_record(self.number)
+            })?;
+        }
+        match lines.next() {
+            Some(extra) => Err(extra
+                .error(extra.text, "a blank line")
+                .in_record(self.number)),
+            None => Ok(result),
+        }
+    }
+
+    /// Moves an error from parsing `text` on its own to where it is in the whole input.
+    pub fn relocate(&self, mut error: ParseError) -> ParseError {
+        error.line += self.start;
+        error.in_record(self.number)
+    }
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Splits the input on blank lines. Any number of blank lines separate two sections, and
+/// lines may end with "\r\n".
+pub fn sections(input: &str) -> impl Iterator<Item = Section<'_>> + '_ {
+    let mut lines = input.lines().enumerate().peekable();
+    let mut number = 0;
+    iter::from_fn(move || {
+        while lines.next_if(|(_, line)| is_blank(line)).is_some() {}
+        let (start, first) = lines.next()?;
+        let mut last = first;
+        while let Some((_, line)) = lines.next_if(|(_, line)| !is_blank(line)) {
+            last = line;
+        }
+
+        let offset = |line: &str| line.as_ptr() as usize - input.as_ptr() as usize;
+        let section = Section {
+            number,
+            start,
+            text: &input[offset(first)..offset(last) + last.len()],
+        };
+        number += 1;
+        Some(section)
+    })
+}
+
+/// Exactly `N` sections, like the grid and the moves of a warehouse.
+pub fn sections_n<const N: usize>(input: &str) -> Result<[Section<'_>; N], ParseError> {
+    let mut found = sections(input);
+    let mut result = [Section {
+        number: 0,
+        start: 0,
+        text: "",
+    }; N];
+    for (count, slot) in result.iter_mut().enumerate() {
+        *slot = found
+            .next()
+            .ok_or_else(|| ParseError::end(input, format!("section {} of {}", count + 1, N)))?;
+    }
+    match found.next() {
+        Some(extra) => {
+            let line = extra.lines().next().unwrap_or_default();
+            Err(line.error(line.text, "end of input"))
+        }
+        None => Ok(result),
+    }
+}
+
+/// Parses every section as a record of exactly `N` lines, so a schema like "Button A,
+/// Button B, Prize" is a closure taking three lines. Errors name the record they are in.
+pub fn records<T, const N: usize>(
+    input: &str,
+    mut parse: impl FnMut([Line<'_>; N]) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    sections(input)
+        .map(|section| {
+            let lines = section.lines_n::<N>()?;
+            parse(lines).map_err(|error| error.in_record(section.number))
+        })
+        .collect()
+}
+
+#[test]
+fn test_ints() {
+    let line = "p=0,4 v=3,-3";
+
+    assert_eq!(ints::<isize>(line).collect::<Vec<_>>(), vec![0, 4, 3, -3]);
+    assert_eq!(ints::<usize>(line).collect::<Vec<_>>(), vec![0, 4, 3]);
+    assert_eq!(ints::<i32>("3   4\t-5").collect::<Vec<_>>(), vec![3, 4, -5]);
+    assert_eq!(ints::<i32>("1-3 a-b -").collect::<Vec<_>>(), vec![1, -3]);
+    assert_eq!(ints::<u8>("255 256 7").collect::<Vec<_>>(), vec![255, 7]);
+}
+
+#[test]
+fn test_ints_n() {
+    assert_eq!(ints_n::<i64, 2>("Button A: X+94, Y+34"), Some([94, 34]));
+    assert_eq!(ints_n::<i64, 2>("Prize: X=8400"), None);
+    assert_eq!(ints_n::<i64, 2>("1 2 3"), None);
+    assert_eq!(ints_n::<u8, 2>("255 256 7"), None);
+    assert_eq!(ints_n::<u8, 2>("255 7"), Some([255, 7]));
+    assert_eq!(ints_n::<u8, 2>("-5 7"), None);
+}
+
+#[test]
+fn test_digits() {
+    assert_eq!(digits("2.3x4").collect::<Vec<_>>(), vec![2, 3, 4]);
+}
+
+#[test]
+fn test_sections() {
+    let input = "\r\na\r\nb\r\n\r\n  \n\nc\n";
+    let found: Vec<Section> = sections(input).collect();
+
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].start, 1);
+    assert_eq!(
+        found[0].lines().map(|x| x.text).collect::<Vec<_>>(),
+        vec!["a", "b"]
+    );
+    assert_eq!(
+        found[1].lines().next(),
+        Some(Line {
+            index: 6,
+            text: "c"
+        })
+    );
+
+    let [first, second] = sections_n(input).unwrap();
+    assert_eq!((first.number, second.number), (0, 1));
+    assert_eq!(
+        sections_n::<3>(input).unwrap_err().expected,
+        "section 3 of 3"
+    );
+}
+
+#[test]
+fn test_records() {
+    let input = "A: 1, 2\nB: 3, 4\n\nA: 5, 6\nB: 7\n\nA: 9, 9\n";
+    let parse = |[a, b]: [Line; 2]| {
+        let a = a.ints_n::<u32, 2>("A: <x>, <y>")?;
+        let b = b.ints_n::<u32, 2>("B: <x>, <y>")?;
+        Ok((a, b))
+    };
+
+    let error = records(input, parse).unwrap_err();
+    assert_eq!(error.record, Some(2));
+    assert_eq!(error.line, 5);
+    assert_eq!(
+        error.to_string(),
+        "record 2, line 5, column 1: expected B: <x>, <y>, found \"B: 7\""
+    );
+
+    let parsed = records("A: 1, 2\nB: 3, 4\n\n\n", parse).unwrap();
+    assert_eq!(parsed, vec![([1, 2], [3, 4])]);
+
+    let error = records("A: 9, 9\n", parse).unwrap_err();
+    assert_eq!(error.expected, "line 2 of 2");
+}
@@ -0,0 +1,407 @@
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, Neg, Rem, Sub};
+
+use crate::{
+    num::{Integer, Signed},
+    vector::Vector,
+};
+
+fn abs<T: Signed>(value: T) -> T {
+    if value < T::ZERO {
+        -value
+    } else {
+        value
+    }
+}
+
+#[derive(Default, PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone, Hash)]
+pub struct Vector3<T = isize> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vector3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Vector3<T> {
+        Vector3 { x, y, z }
+    }
+}
+
+impl<T: Integer> Vector3<T> {
+    pub fn checked_add(&self, other: &Vector3<T>) -> Option<Vector3<T>> {
+        Some(Vector3::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+            self.z.checked_add(other.z)?,
+        ))
+    }
+
+    pub fn convert<U: From<T>>(self) -> Vector3<U> {
+        Vector3::new(U::from(self.x), U::from(self.y), U::from(self.z))
+    }
+}
+
+impl<T: Signed> Vector3<T> {
+    pub fn manhattan(&self) -> T {
+        abs(self.x) + abs(self.y) + abs(self.z)
+    }
+
+    pub fn chebyshev(&self) -> T {
+        abs(self.x).max(abs(self.y)).max(abs(self.z))
+    }
+
+    /// The 6 face-adjacent neighbours.
+    pub fn neighbours(&self) -> impl Iterator<Item = Vector3<T>> + '_ {
+        let one = T::ONE;
+        let zero = T::ZERO;
+        [
+            Vector3::new(one, zero, zero),
+            Vector3::new(-one, zero, zero),
+            Vector3::new(zero, one, zero),
+            Vector3::new(zero, -one, zero),
+            Vector3::new(zero, zero, one),
+            Vector3::new(zero, zero, -one),
+        ]
+        .into_iter()
+        .map(|offset| *self + offset)
+    }
+
+    /// All 26 neighbours, diagonals included.
+    pub fn all_neighbours(&self) -> impl Iterator<Item = Vector3<T>> + '_ {
+        let steps = [-T::ONE, T::ZERO, T::ONE];
+        steps
+            .into_iter()
+            .flat_map(move |x| {
+                steps
+                    .into_iter()
+                    .flat_map(move |y| steps.into_iter().map(move |z| Vector3::new(x, y, z)))
+            })
+            .filter(|offset| *offset != Vector3::default())
+            .map(|offset| *self + offset)
+    }
+}
+
+impl<T: Integer> AddAssign<Vector3<T>> for Vector3<T> {
+    fn add_assign(&mut self, rhs: Vector3<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T: Integer> Add<Vector3<T>> for Vector3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Vector3<T>) -> Self::Output {
+        Vector3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Integer> Add<&Vector3<T>> for &Vector3<T> {
+    type Output = Vector3<T>;
+
+    fn add(self, rhs: &Vector3<T>) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+impl<T: Integer> Sub<Vector3<T>> for Vector3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector3<T>) -> Self::Output {
+        Vector3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Integer> Sub<&Vector3<T>> for &Vector3<T> {
+    type Output = Vector3<T>;
+
+    fn sub(self, rhs: &Vector3<T>) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl<T: Integer> Mul<T> for Vector3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Vector3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Integer> Div<T> for Vector3<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Vector3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl<T: Integer> Rem<T> for Vector3<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        Vector3::new(self.x % rhs, self.y % rhs, self.z % rhs)
+    }
+}
+
+impl<T: Signed> Neg for Vector3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T> From<(T, T, T)> for Vector3<T> {
+    fn from(value: (T, T, T)) -> Self {
+        Vector3::new(value.0, value.1, value.2)
+    }
+}
+
+/// Point with any number of dimensions.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone, Hash)]
+pub struct Point<const N: usize, T = isize> {
+    pub coords: [T; N],
+}
+
+impl<const N: usize, T> Point<N, T> {
+    pub const fn new(coords: [T; N]) -> Point<N, T> {
+        Point { coords }
+    }
+}
+
+impl<const N: usize, T: Integer> Point<N, T> {
+    pub fn checked_add(&self, other: &Point<N, T>) -> Option<Point<N, T>> {
+        let mut sum = *self;
+        for (coord, other) in sum.coords.iter_mut().zip(other.coords) {
+            *coord = coord.checked_add(other)?;
+        }
+        Some(sum)
+    }
+
+    pub fn convert<U: From<T>>(self) -> Point<N, U> {
+        Point::new(self.coords.map(U::from))
+    }
+}
+
+impl<const N: usize, T: Signed> Point<N, T> {
+    pub fn manhattan(&self) -> T {
+        self.coords
+            .iter()
+            .fold(T::ZERO, |total, coord| total + abs(*coord))
+    }
+
+    pub fn chebyshev(&self) -> T {
+        self.coords
+            .iter()
+            .map(|coord| abs(*coord))
+            .max()
+            .unwrap_or(T::ZERO)
+    }
+
+    /// The 2N neighbours that differ by one along a single axis.
+    pub fn neighbours(&self) -> impl Iterator<Item = Point<N, T>> + '_ {
+        (0..N).flat_map(move |axis| {
+            [-T::ONE, T::ONE].into_iter().map(move |step| {
+                let mut neighbour = *self;
+                neighbour.coords[axis] += step;
+                neighbour
+            })
+        })
+    }
+
+    /// All 3^N - 1 neighbours, diagonals included.
+    pub fn all_neighbours(&self) -> impl Iterator<Item = Point<N, T>> + '_ {
+        let steps = [-T::ONE, T::ZERO, T::ONE];
+        let count = 3usize.pow(N as u32);
+        (0..count)
+            .filter(move |offset| *offset != count / 2)
+            .map(move |mut offset| {
+                let mut neighbour = *self;
+                for coord in neighbour.coords.iter_mut() {
+                    *coord += steps[offset % 3];
+                    offset /= 3;
+                }
+                neighbour
+            })
+    }
+}
+
+impl<const N: usize, T: Integer> Default for Point<N, T> {
+    fn default() -> Self {
+        Point::new([T::ZERO; N])
+    }
+}
+
+impl<const N: usize, T> Index<usize> for Point<N, T> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.coords[axis]
+    }
+}
+
+impl<const N: usize, T> IndexMut<usize> for Point<N, T> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.coords[axis]
+    }
+}
+
+impl<const N: usize, T: Integer> AddAssign<Point<N, T>> for Point<N, T> {
+    fn add_assign(&mut self, rhs: Point<N, T>) {
+        for (coord, other) in self.coords.iter_mut().zip(rhs.coords) {
+            *coord += other;
+        }
+    }
+}
+
+impl<const N: usize, T: Integer> Add<Point<N, T>> for Point<N, T> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Point<N, T>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const N: usize, T: Integer> Add<&Point<N, T>> for &Point<N, T> {
+    type Output = Point<N, T>;
+
+    fn add(self, rhs: &Point<N, T>) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+impl<const N: usize, T: Integer> Sub<Point<N, T>> for Point<N, T> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Point<N, T>) -> Self::Output {
+        for (coord, other) in self.coords.iter_mut().zip(rhs.coords) {
+            *coord -= other;
+        }
+        self
+    }
+}
+
+impl<const N: usize, T: Integer> Sub<&Point<N, T>> for &Point<N, T> {
+    type Output = Point<N, T>;
+
+    fn sub(self, rhs: &Point<N, T>) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl<const N: usize, T: Integer> Mul<T> for Point<N, T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point::new(self.coords.map(|x| x * rhs))
+    }
+}
+
+impl<const N: usize, T: Integer> Div<T> for Point<N, T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Point::new(self.coords.map(|x| x / rhs))
+    }
+}
+
+impl<const N: usize, T: Integer> Rem<T> for Point<N, T> {
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        Point::new(self.coords.map(|x| x % rhs))
+    }
+}
+
+impl<const N: usize, T: Signed> Neg for Point<N, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Point::new(self.coords.map(|x| -x))
+    }
+}
+
+impl<const N: usize, T> From<[T; N]> for Point<N, T> {
+    fn from(coords: [T; N]) -> Self {
+        Point::new(coords)
+    }
+}
+
+impl<T> From<Vector<T>> for Point<2, T> {
+    fn from(value: Vector<T>) -> Self {
+        Point::new([value.x, value.y])
+    }
+}
+
+impl<T> From<Point<2, T>> for Vector<T> {
+    fn from(value: Point<2, T>) -> Self {
+        let [x, y] = value.coords;
+        Vector::new(x, y)
+    }
+}
+
+impl<T> From<Vector3<T>> for Point<3, T> {
+    fn from(value: Vector3<T>) -> Self {
+        Point::new([value.x, value.y, value.z])
+    }
+}
+
+impl<T> From<Point<3, T>> for Vector3<T> {
+    fn from(value: Point<3, T>) -> Self {
+        let [x, y, z] = value.coords;
+        Vector3::new(x, y, z)
+    }
+}
+
+#[test]
+fn test_vector3() {
+    let a = Vector3::new(1, -2, 3);
+    let b = Vector3::new(4, 5, -6);
+
+    assert_eq!(a + b, Vector3::new(5, 3, -3));
+    assert_eq!(a - b, Vector3::new(-3, -7, 9));
+    assert_eq!(a * 2, Vector3::new(2, -4, 6));
+    assert_eq!(a.manhattan(), 6);
+    assert_eq!(b.chebyshev(), 6);
+    assert_eq!(a.neighbours().count(), 6);
+    assert_eq!(a.all_neighbours().count(), 26);
+}
+
+#[test]
+fn test_point_neighbours() {
+    let point = Point::new([0, 0, 0, 0]);
+
+    assert_eq!(point.neighbours().count(), 8);
+    assert_eq!(point.all_neighbours().count(), 80);
+    assert!(point.all_neighbours().all(|x| x.chebyshev() == 1));
+    assert!(point.neighbours().all(|x| x.manhattan() == 1));
+}
+
+#[test]
+fn test_point_conversion() {
+    let vector = Vector::new(3, -4);
+    let point = Point::from(vector);
+
+    assert_eq!(point, Point::new([3, -4]));
+    assert_eq!(Vector::from(point + Point::new([1, 1])), Vector::new(4, -3));
+    assert_eq!(
+        Vector3::from(Point::from(Vector3::new(1, 2, 3))),
+        Vector3::new(1, 2, 3)
+    );
+}
+
+#[test]
+fn test_point_generic() {
+    let point: Point<3, i64> = Point::new([7, -9, 4]);
+
+    assert_eq!(point / 2, Point::new([3, -4, 2]));
+    assert_eq!(point % 2, Point::new([1, -1, 0]));
+    assert_eq!(point.manhattan(), 20);
+    assert_eq!(point.convert::<i128>(), Point::new([7i128, -9, 4]));
+    assert_eq!(
+        Point::new([u8::MAX, 0]).checked_add(&Point::new([1, 0])),
+        None
+    );
+}
@@ -0,0 +1,236 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::math::checked_gcd;
+
+/// Exact fraction. Always normalised: the denominator is positive and shares no factor with
+/// the numerator, so equal values compare equal. The operators panic on overflow like the
+/// integer ones, the `checked_` methods return None instead.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational {
+        numerator: 0,
+        denominator: 1,
+    };
+    pub const ONE: Rational = Rational {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// Panics when `denominator` is 0.
+    pub fn new(numerator: i128, denominator: i128) -> Rational {
+        Rational::checked_new(numerator, denominator).expect("denominator should not be 0")
+    }
+
+    /// None when `denominator` is 0 or the sign cannot be moved to the numerator.
+    pub fn checked_new(numerator: i128, denominator: i128) -> Option<Rational> {
+        if denominator == 0 {
+            return None;
+        }
+        // The gcd only fails to fit when it is 2^127, and dividing by MIN instead leaves the
+        // same fraction
+        let divisor = checked_gcd(numerator, denominator).unwrap_or(i128::MIN);
+        let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+        match denominator < 0 {
+            true => Some(Rational {
+                numerator: numerator.checked_neg()?,
+                denominator: denominator.checked_neg()?,
+            }),
+            false => Some(Rational {
+                numerator,
+                denominator,
+            }),
+        }
+    }
+
+    pub fn numerator(self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.denominator == 1
+    }
+
+    /// The value when it is a whole number.
+    pub fn to_integer(self) -> Option<i128> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    pub fn signum(self) -> i128 {
+        self.numerator.signum()
+    }
+
+    pub fn checked_neg(self) -> Option<Rational> {
+        Some(Rational {
+            numerator: self.numerator.checked_neg()?,
+            denominator: self.denominator,
+        })
+    }
+
+    /// One over the value, None for 0.
+    pub fn checked_recip(self) -> Option<Rational> {
+        Rational::checked_new(self.denominator, self.numerator)
+    }
+
+    pub fn checked_add(self, other: Rational) -> Option<Rational> {
+        // Scaling to the lcm of the denominators keeps the numbers small
+        let divisor = checked_gcd(self.denominator, other.denominator)?;
+        let denominator = (self.denominator / divisor).checked_mul(other.denominator)?;
+        let numerator = self
+            .numerator
+            .checked_mul(other.denominator / divisor)?
+            .checked_add(other.numerator.checked_mul(self.denominator / divisor)?)?;
+        Rational::checked_new(numerator, denominator)
+    }
+
+    pub fn checked_sub(self, other: Rational) -> Option<Rational> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, other: Rational) -> Option<Rational> {
+        // Cancelling across first, so the products only overflow when the result would
+        let first = checked_gcd(self.numerator, other.denominator)?;
+        let second = checked_gcd(other.numerator, self.denominator)?;
+        let numerator = (self.numerator / first).checked_mul(other.numerator / second)?;
+        let denominator = (self.denominator / second).checked_mul(other.denominator / first)?;
+        Rational::checked_new(numerator, denominator)
+    }
+
+    /// None when `other` is 0 or on overflow.
+    pub fn checked_div(self, other: Rational) -> Option<Rational> {
+        self.checked_mul(other.checked_recip()?)
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::ZERO
+    }
+}
+
+macro_rules! impl_rational_from_integer {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Rational {
+                fn from(value: $type) -> Self {
+                    Rational {
+                        numerator: value as i128,
+                        denominator: 1,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_rational_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.denominator {
+            1 => write!(f, "{}", self.numerator),
+            denominator => write!(f, "{}/{}", self.numerator, denominator),
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("rational negation overflowed")
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Rational) -> Self::Output {
+        self.checked_add(rhs).expect("rational addition overflowed")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Rational) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("rational subtraction overflowed")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("rational multiplication overflowed")
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: Rational) -> Self::Output {
+        match rhs.is_zero() {
+            true => panic!("division of a rational by zero"),
+            false => self.checked_div(rhs).expect("rational division overflowed"),
+        }
+    }
+}
+
+#[test]
+fn test_normalise() {
+    assert_eq!(Rational::new(6, -8), Rational::new(-3, 4));
+    assert_eq!(Rational::new(0, -5), Rational::ZERO);
+    assert_eq!(Rational::new(-3, 4).to_string(), "-3/4");
+    assert_eq!(Rational::new(8, 4).to_integer(), Some(2));
+    assert_eq!(Rational::checked_new(1, 0), None);
+    assert_eq!(Rational::checked_new(1, i128::MIN), None);
+    assert_eq!(Rational::checked_new(i128::MIN, -1), None);
+    assert_eq!(
+        Rational::checked_new(i128::MIN, 1),
+        Some(Rational::from(i128::MIN))
+    );
+    assert_eq!(
+        Rational::checked_new(i128::MIN, i128::MIN),
+        Some(Rational::ONE)
+    );
+    assert_eq!(Rational::checked_new(0, i128::MIN), Some(Rational::ZERO));
+}
+
+#[test]
+fn test_arithmetic() {
+    let half = Rational::new(1, 2);
+    let third = Rational::new(1, 3);
+
+    assert_eq!(half + third, Rational::new(5, 6));
+    assert_eq!(half - third, Rational::new(1, 6));
+    assert_eq!(half * third, Rational::new(1, 6));
+    assert_eq!(half / third, Rational::new(3, 2));
+    assert_eq!(-half + Rational::from(1), half);
+    assert_eq!(half.checked_div(Rational::ZERO), None);
+
+    let huge = Rational::from(i128::MAX);
+    assert_eq!(huge.checked_add(Rational::ONE), None);
+    assert_eq!(
+        huge.checked_mul(Rational::new(1, 2)),
+        Some(Rational::new(i128::MAX, 2))
+    );
+    assert_eq!((huge * Rational::new(3, i128::MAX)).to_integer(), Some(3));
+}
@@ -0,0 +1,154 @@
+use std::cmp::{max, min};
+
+use crate::vector::Vector;
+
+/// Axis aligned rectangle. `x` and `y` are inclusive, `x + width` and `y + height` are not.
+#[derive(Default, PartialEq, Eq, Debug, Copy, Clone, Hash)]
+pub struct Rect {
+    pub x: isize,
+    pub y: isize,
+    pub width: isize,
+    pub height: isize,
+}
+
+impl Rect {
+    pub const fn new(x: isize, y: isize, width: isize, height: isize) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Bounds of a text grid. Width is the longest line, so CRLF and a missing trailing newline do not matter.
+    pub fn from_text(input: &str) -> Rect {
+        let height = input.lines().count();
+        let width = input
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        Rect::new(0, 0, width as isize, height as isize)
+    }
+
+    pub fn right(&self) -> isize {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> isize {
+        self.y + self.height
+    }
+
+    pub fn area(&self) -> isize {
+        self.width * self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width <= 0 || self.height <= 0
+    }
+
+    pub fn contains(&self, vector: &Vector) -> bool {
+        vector.x >= self.x
+            && vector.x < self.right()
+            && vector.y >= self.y
+            && vector.y < self.bottom()
+    }
+
+    /// Wraps the vector around the edges as if the rect was a torus. None when the rect is
+    /// empty, as nothing is inside it to wrap to.
+    pub fn wrap(&self, vector: Vector) -> Option<Vector> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(Vector::new(
+            self.x + (vector.x - self.x).rem_euclid(self.width),
+            self.y + (vector.y - self.y).rem_euclid(self.height),
+        ))
+    }
+
+    /// All points in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Vector> {
+        let rect = *self;
+        (rect.y..rect.bottom())
+            .flat_map(move |y| (rect.x..rect.right()).map(move |x| Vector::new(x, y)))
+    }
+
+    /// Splits into top left, top right, bottom left and bottom right quadrants.
+    /// With an odd width or height the middle column or row is not part of any quadrant.
+    pub fn split_quadrants(&self) -> [Rect; 4] {
+        let left_width = self.width / 2;
+        let right_x = self.x + (self.width + 1) / 2;
+        let top_height = self.height / 2;
+        let bottom_y = self.y + (self.height + 1) / 2;
+        [
+            Rect::new(self.x, self.y, left_width, top_height),
+            Rect::new(right_x, self.y, left_width, top_height),
+            Rect::new(self.x, bottom_y, left_width, top_height),
+            Rect::new(right_x, bottom_y, left_width, top_height),
+        ]
+    }
+
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let x = max(self.x, other.x);
+        let y = max(self.y, other.y);
+        let rect = Rect::new(
+            x,
+            y,
+            min(self.right(), other.right()) - x,
+            min(self.bottom(), other.bottom()) - y,
+        );
+        if rect.is_empty() {
+            None
+        } else {
+            Some(rect)
+        }
+    }
+
+    /// Smallest rect that covers both.
+    pub fn union(&self, other: &Rect) -> Rect {
+        let x = min(self.x, other.x);
+        let y = min(self.y, other.y);
+        Rect::new(
+            x,
+            y,
+            max(self.right(), other.right()) - x,
+            max(self.bottom(), other.bottom()) - y,
+        )
+    }
+}
+
+#[test]
+fn test_from_text() {
+    assert_eq!(Rect::from_text("abc\r\ndef\r\n"), Rect::new(0, 0, 3, 2));
+    assert_eq!(Rect::from_text("abc\ndef"), Rect::new(0, 0, 3, 2));
+}
+
+#[test]
+fn test_wrap() {
+    let rect = Rect::new(0, 0, 11, 7);
+    assert_eq!(rect.wrap(Vector::new(-2, 8)), Some(Vector::new(9, 1)));
+    assert_eq!(rect.wrap(Vector::new(11, 6)), Some(Vector::new(0, 6)));
+    assert_eq!(Rect::new(0, 0, 0, 7).wrap(Vector::new(1, 1)), None);
+}
+
+#[test]
+fn test_split_quadrants() {
+    let [top_left, top_right, bottom_left, bottom_right] = Rect::new(0, 0, 11, 7).split_quadrants();
+    assert_eq!(top_left, Rect::new(0, 0, 5, 3));
+    assert_eq!(top_right, Rect::new(6, 0, 5, 3));
+    assert_eq!(bottom_left, Rect::new(0, 4, 5, 3));
+    assert_eq!(bottom_right, Rect::new(6, 4, 5, 3));
+    assert!(!top_left.contains(&Vector::new(5, 0)));
+}
+
+#[test]
+fn test_intersection_and_union() {
+    let a = Rect::new(0, 0, 4, 4);
+    let b = Rect::new(2, 3, 4, 4);
+    assert_eq!(a.intersection(&b), Some(Rect::new(2, 3, 2, 1)));
+    assert_eq!(a.intersection(&Rect::new(4, 0, 1, 1)), None);
+    assert_eq!(a.union(&b), Rect::new(0, 0, 6, 7));
+    assert_eq!(b.points().count(), 16);
+    assert_eq!(a.points().nth(5), Some(Vector::new(1, 1)));
+}
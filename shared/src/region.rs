@@ -0,0 +1,234 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    components::label,
+    grid::Grid,
+    rect::Rect,
+    vector::{Direction, Vector},
+};
+
+/// Corners of a region. Convex ones point outwards, concave ones point into the region.
+#[derive(Default, PartialEq, Eq, Debug, Copy, Clone)]
+pub struct Corners {
+    pub convex: usize,
+    pub concave: usize,
+}
+
+impl Corners {
+    pub fn total(&self) -> usize {
+        self.convex + self.concave
+    }
+}
+
+pub fn area(cells: &HashSet<Vector>) -> usize {
+    cells.len()
+}
+
+/// Every cell side that faces something outside the region, as the cell and the side.
+pub fn perimeter_edges(cells: &HashSet<Vector>) -> Vec<(Vector, Direction)> {
+    let mut edges = vec![];
+    for cell in cells {
+        for direction in Direction::CARDINALS {
+            if !cells.contains(&(*cell + direction)) {
+                edges.push((*cell, direction));
+            }
+        }
+    }
+    edges
+}
+
+pub fn perimeter(cells: &HashSet<Vector>) -> usize {
+    perimeter_edges(cells).len()
+}
+
+/// Cells that only touch diagonally are counted as separate corners.
+pub fn corners(cells: &HashSet<Vector>) -> Corners {
+    let mut corners = Corners::default();
+    for cell in cells {
+        for first in Direction::CARDINALS {
+            let second = first.turn_right();
+            let first_inside = cells.contains(&(*cell + first));
+            let second_inside = cells.contains(&(*cell + second));
+            let diagonal_inside = cells.contains(&(*cell + first + second));
+            if !first_inside && !second_inside {
+                corners.convex += 1;
+            } else if first_inside && second_inside && !diagonal_inside {
+                corners.concave += 1;
+            }
+        }
+    }
+    corners
+}
+
+/// Number of straight fence sides, which is the same as the number of corners.
+pub fn sides(cells: &HashSet<Vector>) -> usize {
+    corners(cells).total()
+}
+
+pub fn bounding_rect(cells: &HashSet<Vector>) -> Option<Rect> {
+    let min_x = cells.iter().map(|x| x.x).min()?;
+    let min_y = cells.iter().map(|x| x.y).min()?;
+    let max_x = cells.iter().map(|x| x.x).max()?;
+    let max_y = cells.iter().map(|x| x.y).max()?;
+    Some(Rect::new(
+        min_x,
+        min_y,
+        max_x - min_x + 1,
+        max_y - min_y + 1,
+    ))
+}
+
+/// Groups of cells outside the region that cannot reach the outside with orthogonal steps.
+pub fn holes(cells: &HashSet<Vector>) -> Vec<Vec<Vector>> {
+    let Some(bounds) = bounding_rect(cells) else {
+        return vec![];
+    };
+    // One empty cell of padding on every side, so everything outside is connected
+    let origin = Vector::new(bounds.x - 1, bounds.y - 1);
+    let mut grid = Grid::new(bounds.width + 2, bounds.height + 2, false);
+    for cell in cells {
+        grid[*cell - origin] = true;
+    }
+
+    let components = label(&grid, false, |a, b| a == b);
+    let outside = components.labels[Vector::new(0, 0)];
+    components
+        .cells
+        .into_iter()
+        .enumerate()
+        .filter(|(index, component)| *index != outside && !grid[component[0]])
+        .map(|(_, component)| component.into_iter().map(|x| x + origin).collect())
+        .collect()
+}
+
+/// Boundary as closed polygons. Vertices are cell corners, so cell `(x, y)` spans from `(x, y)`
+/// to `(x + 1, y + 1)`, and only vertices where the boundary turns are kept. Outer boundaries go
+/// clockwise and holes counter-clockwise, each starting from its smallest vertex.
+pub fn boundary_loops(cells: &HashSet<Vector>) -> Vec<Vec<Vector>> {
+    // Edges keep the region on their right hand side
+    let mut edges: HashMap<Vector, Vec<Direction>> = HashMap::new();
+    for (cell, side) in perimeter_edges(cells) {
+        let (start, direction) = match side {
+            Direction::Up => (cell, Direction::Right),
+            Direction::Right => (cell + Vector::new(1, 0), Direction::Down),
+            Direction::Down => (cell + Vector::new(1, 1), Direction::Left),
+            _ => (cell + Vector::new(0, 1), Direction::Up),
+        };
+        edges.entry(start).or_default().push(direction);
+    }
+
+    let mut starts: Vec<Vector> = edges.keys().copied().collect();
+    starts.sort();
+
+    let mut loops = vec![];
+    for start in starts {
+        while let Some(direction) = edges.get_mut(&start).and_then(|x| x.pop()) {
+            loops.push(trace_loop(&mut edges, start, direction));
+        }
+    }
+    loops
+}
+
+fn trace_loop(
+    edges: &mut HashMap<Vector, Vec<Direction>>,
+    start: Vector,
+    first_direction: Direction,
+) -> Vec<Vector> {
+    let mut vertices = vec![];
+    let mut position = start + first_direction;
+    let mut direction = first_direction;
+
+    loop {
+        let outgoing = edges.entry(position).or_default();
+        // Turning right first keeps regions that only touch diagonally apart
+        let next = [direction.turn_right(), direction, direction.turn_left()]
+            .into_iter()
+            .find(|x| outgoing.contains(x) || (position == start && *x == first_direction))
+            .expect("boundary should be closed");
+        if next != direction {
+            vertices.push(position);
+        }
+        if position == start && next == first_direction {
+            break;
+        }
+        outgoing.retain(|x| *x != next);
+        direction = next;
+        position += direction;
+    }
+
+    let smallest = (0..vertices.len())
+        .min_by_key(|x| vertices[*x])
+        .unwrap_or(0);
+    vertices.rotate_left(smallest);
+    vertices
+}
+
+#[cfg(test)]
+fn cells_of(input: &str) -> HashSet<Vector> {
+    Grid::parse(input, |char| char == '#')
+        .iter()
+        .filter(|x| *x.1)
+        .map(|x| x.0)
+        .collect()
+}
+
+#[test]
+fn test_square() {
+    let cells = cells_of("##\n##");
+
+    assert_eq!(area(&cells), 4);
+    assert_eq!(perimeter(&cells), 8);
+    assert_eq!(sides(&cells), 4);
+    assert_eq!(bounding_rect(&cells), Some(Rect::new(0, 0, 2, 2)));
+    assert_eq!(
+        boundary_loops(&cells),
+        vec![vec![
+            Vector::new(0, 0),
+            Vector::new(2, 0),
+            Vector::new(2, 2),
+            Vector::new(0, 2)
+        ]]
+    );
+}
+
+#[test]
+fn test_concave_corners() {
+    // Same shape as the C region in the second example of day 12
+    let cells = cells_of("..\n#.\n##\n.#");
+
+    assert_eq!(
+        corners(&cells),
+        Corners {
+            convex: 6,
+            concave: 2
+        }
+    );
+    assert_eq!(sides(&cells), 8);
+    assert_eq!(perimeter(&cells), 10);
+    assert_eq!(boundary_loops(&cells)[0].len(), 8);
+}
+
+#[test]
+fn test_holes() {
+    let cells = cells_of("#####\n#.#.#\n#####\n#...#\n.###.");
+    let mut holes = holes(&cells);
+    holes.sort();
+
+    assert_eq!(holes.len(), 3);
+    assert_eq!(holes[0], vec![Vector::new(1, 1)]);
+    assert_eq!(holes[1].len(), 3);
+
+    let loops = boundary_loops(&cells);
+    assert_eq!(loops.len(), 4);
+    let loop_sides: usize = loops.iter().map(|x| x.len()).sum();
+    assert_eq!(loop_sides, sides(&cells));
+}
+
+#[test]
+fn test_diagonal_touch() {
+    let cells = cells_of("#.\n.#");
+
+    assert_eq!(sides(&cells), 8);
+    assert_eq!(boundary_loops(&cells).len(), 2);
+    assert!(holes(&cells).is_empty());
+}
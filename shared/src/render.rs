@@ -0,0 +1,309 @@
+use std::fmt::{self, Display};
+
+use crate::{
+    grid::Grid,
+    map::Map,
+    rect::Rect,
+    vector::{Direction, Vector},
+};
+
+/// Foreground colours of the basic ANSI palette.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+}
+
+impl Colour {
+    fn code(self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+            Colour::White => 37,
+            Colour::Grey => 90,
+        }
+    }
+}
+
+/// One drawn tile.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub char: char,
+    pub colour: Option<Colour>,
+}
+
+impl Cell {
+    pub fn new(char: char, colour: Colour) -> Cell {
+        Cell {
+            char,
+            colour: Some(colour),
+        }
+    }
+}
+
+impl From<char> for Cell {
+    fn from(char: char) -> Self {
+        Cell { char, colour: None }
+    }
+}
+
+/// How a tile looks when drawn.
+pub trait Glyph {
+    fn glyph(&self) -> char;
+
+    fn colour(&self) -> Option<Colour> {
+        None
+    }
+
+    fn cell(&self) -> Cell {
+        Cell {
+            char: self.glyph(),
+            colour: self.colour(),
+        }
+    }
+}
+
+impl<T: Glyph + ?Sized> Glyph for &T {
+    fn glyph(&self) -> char {
+        (**self).glyph()
+    }
+
+    fn colour(&self) -> Option<Colour> {
+        (**self).colour()
+    }
+}
+
+impl Glyph for char {
+    fn glyph(&self) -> char {
+        *self
+    }
+}
+
+impl Glyph for Cell {
+    fn glyph(&self) -> char {
+        self.char
+    }
+
+    fn colour(&self) -> Option<Colour> {
+        self.colour
+    }
+}
+
+/// Arrows for the cardinal directions, slashes for the diagonals.
+impl Glyph for Direction {
+    fn glyph(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::UpRight | Direction::DownLeft => '/',
+            Direction::DownRight | Direction::UpLeft => '\\',
+        }
+    }
+}
+
+/// Walls and open space, like a map of obstacles.
+impl Glyph for bool {
+    fn glyph(&self) -> char {
+        match self {
+            true => '#',
+            false => '.',
+        }
+    }
+}
+
+/// Tiles drawn into a rect, with layers painted on top. Printing a grid with the guard on it
+/// is `println!("{}", Canvas::from_grid(&grid).overlay([(guard, '^')]))`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    bounds: Rect,
+    cells: Vec<Cell>,
+    viewport: Option<Rect>,
+    rulers: bool,
+    colours: bool,
+}
+
+impl Canvas {
+    /// Every cell of `bounds` is `background`.
+    pub fn new(bounds: Rect, background: impl Into<Cell>) -> Canvas {
+        Canvas {
+            bounds,
+            cells: vec![background.into(); bounds.area().max(0) as usize],
+            viewport: None,
+            rulers: false,
+            colours: false,
+        }
+    }
+
+    pub fn from_grid<T: Glyph>(grid: &Grid<T>) -> Canvas {
+        let bounds = Rect::new(0, 0, grid.width, grid.height);
+        Canvas::new(bounds, ' ').overlay(grid.iter())
+    }
+
+    /// Positions missing from the map are blank.
+    pub fn from_map<T: Glyph>(map: &Map<T>) -> Canvas {
+        let bounds = Rect::new(0, 0, map.width, map.height);
+        Canvas::new(bounds, ' ').overlay(map.iter().map(|(position, tile)| (*position, tile)))
+    }
+
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn index_of(&self, position: Vector) -> Option<usize> {
+        self.bounds.contains(&position).then(|| {
+            ((position.y - self.bounds.y) * self.bounds.width + position.x - self.bounds.x) as usize
+        })
+    }
+
+    pub fn get(&self, position: Vector) -> Option<Cell> {
+        self.index_of(position).map(|index| self.cells[index])
+    }
+
+    /// Draws one tile, positions outside the canvas are ignored.
+    pub fn set(&mut self, position: Vector, glyph: impl Glyph) {
+        if let Some(index) = self.index_of(position) {
+            self.cells[index] = glyph.cell();
+        }
+    }
+
+    /// Draws a layer on top of what is there, like a path or the robots.
+    pub fn overlay<G: Glyph>(mut self, layer: impl IntoIterator<Item = (Vector, G)>) -> Canvas {
+        for (position, glyph) in layer {
+            self.set(position, glyph);
+        }
+        self
+    }
+
+    /// Colours the cells at `positions` and keeps their chars.
+    pub fn highlight(
+        mut self,
+        positions: impl IntoIterator<Item = Vector>,
+        colour: Colour,
+    ) -> Canvas {
+        for position in positions {
+            if let Some(index) = self.index_of(position) {
+                self.cells[index].colour = Some(colour);
+            }
+        }
+        self
+    }
+
+    /// Only draws the part of the canvas inside `viewport`.
+    pub fn viewport(mut self, viewport: Rect) -> Canvas {
+        self.viewport = Some(viewport);
+        self
+    }
+
+    /// Numbers the columns above the canvas and the rows on its left.
+    pub fn rulers(mut self) -> Canvas {
+        self.rulers = true;
+        self
+    }
+
+    /// Writes colours as ANSI escape codes, without them colours are dropped.
+    pub fn colours(mut self) -> Canvas {
+        self.colours = true;
+        self
+    }
+
+    fn visible(&self) -> Rect {
+        match self.viewport {
+            Some(viewport) => self.bounds.intersection(&viewport).unwrap_or_default(),
+            None => self.bounds,
+        }
+    }
+
+    fn write_cell(&self, f: &mut fmt::Formatter<'_>, cell: Cell) -> fmt::Result {
+        match cell.colour {
+            Some(colour) if self.colours => {
+                write!(f, "\x1b[{}m{}\x1b[0m", colour.code(), cell.char)
+            }
+            _ => write!(f, "{}", cell.char),
+        }
+    }
+}
+
+impl Display for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let visible = self.visible();
+        let label_width = match self.rulers {
+            true => [visible.y, visible.bottom() - 1]
+                .iter()
+                .map(|x| x.to_string().len())
+                .max()
+                .unwrap_or(0),
+            false => 0,
+        };
+
+        if self.rulers {
+            // Column numbers are written top to bottom, one digit per row
+            let labels: Vec<String> = (visible.x..visible.right())
+                .map(|x| x.to_string())
+                .collect();
+            let digits = labels.iter().map(String::len).max().unwrap_or(0);
+            for row in 0..digits {
+                write!(f, "{:width$} ", "", width = label_width)?;
+                for label in &labels {
+                    let padding = digits - label.len();
+                    let char = match row.checked_sub(padding) {
+                        Some(at) => label.as_bytes()[at] as char,
+                        None => ' ',
+                    };
+                    write!(f, "{}", char)?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        for y in visible.y..visible.bottom() {
+            if self.rulers {
+                write!(f, "{:>width$} ", y, width = label_width)?;
+            }
+            for x in visible.x..visible.right() {
+                let cell = self.get(Vector::new(x, y)).unwrap_or(Cell::from(' '));
+                self.write_cell(f, cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_overlay() {
+    let grid = Grid::parse("..#\n...", |char| char == '#');
+    let canvas =
+        Canvas::from_grid(&grid).overlay([(Vector::new(0, 1), '@'), (Vector::new(5, 5), '@')]);
+
+    assert_eq!(canvas.to_string(), "..#\n@..\n");
+    assert_eq!(
+        canvas.clone().viewport(Rect::new(1, 1, 5, 5)).to_string(),
+        "..\n"
+    );
+    assert_eq!(
+        canvas
+            .highlight([Vector::new(2, 0)], Colour::Red)
+            .colours()
+            .to_string(),
+        "..\x1b[31m#\x1b[0m\n@..\n"
+    );
+}
+
+#[test]
+fn test_rulers() {
+    let canvas = Canvas::new(Rect::new(8, 9, 4, 2), '.').rulers();
+
+    assert_eq!(canvas.to_string(), "     11\n   8901\n 9 ....\n10 ....\n");
+}
@@ -0,0 +1,328 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::num::Integer;
+
+/// Everything a search found out. `distances` holds the best cost to every state that was
+/// reached and `predecessors` every state that reaches it with that cost. Predecessor sets are
+/// only acyclic when all costs are positive.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    pub start: S,
+    pub distances: HashMap<S, C>,
+    pub predecessors: HashMap<S, Vec<S>>,
+    /// Goal states reached with the optimal cost.
+    pub goals: Vec<S>,
+}
+
+impl<S: Eq + Hash + Clone, C: Integer> Search<S, C> {
+    fn new(start: S) -> Search<S, C> {
+        Search {
+            distances: HashMap::from([(start.clone(), C::ZERO)]),
+            predecessors: HashMap::new(),
+            goals: vec![],
+            start,
+        }
+    }
+
+    /// Returns true if `state` should be pushed to the frontier.
+    fn relax(&mut self, from: &S, state: S, cost: C) -> bool {
+        if state == self.start {
+            return false;
+        }
+        match self.distances.get(&state) {
+            Some(known) if cost > *known => false,
+            Some(known) if cost == *known => {
+                self.predecessors
+                    .entry(state)
+                    .or_default()
+                    .push(from.clone());
+                false
+            }
+            _ => {
+                self.distances.insert(state.clone(), cost);
+                self.predecessors.insert(state, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    pub fn cost(&self) -> Option<C> {
+        self.goals.first().map(|goal| self.distances[goal])
+    }
+
+    /// One optimal path from the start to the first goal, both included.
+    pub fn path(&self) -> Option<Vec<S>> {
+        let mut state = self.goals.first()?.clone();
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors.get(&state).and_then(|x| x.first()) {
+            state = previous.clone();
+            path.push(state.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every optimal path to every goal. Can be exponential, see `path_count`.
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        let mut result = vec![];
+        let mut stack: Vec<Vec<S>> = self.goals.iter().map(|x| vec![x.clone()]).collect();
+        while let Some(path) = stack.pop() {
+            let last = path.last().expect("paths are never empty");
+            match self.predecessors.get(last) {
+                Some(previous) => {
+                    for state in previous {
+                        let mut next = path.clone();
+                        next.push(state.clone());
+                        stack.push(next);
+                    }
+                }
+                None => {
+                    let mut path = path;
+                    path.reverse();
+                    result.push(path);
+                }
+            }
+        }
+        result
+    }
+
+    /// Number of optimal paths to all goals, without listing them. States are counted in
+    /// order of distance, so every predecessor is done before the states it leads to.
+    pub fn path_count(&self) -> usize {
+        let mut states: Vec<S> = self.on_optimal_paths().into_iter().collect();
+        states.sort_by_key(|state| self.distances[state]);
+
+        let mut counts: HashMap<S, usize> = HashMap::new();
+        for state in states {
+            let count = match self.predecessors.get(&state) {
+                Some(previous) => previous.iter().map(|x| counts[x]).sum(),
+                None => 1,
+            };
+            counts.insert(state, count);
+        }
+        self.goals.iter().map(|goal| counts[goal]).sum()
+    }
+
+    /// States that are part of at least one optimal path.
+    pub fn on_optimal_paths(&self) -> HashSet<S> {
+        let mut result: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut stack = self.goals.clone();
+        while let Some(state) = stack.pop() {
+            for previous in self.predecessors.get(&state).into_iter().flatten() {
+                if result.insert(previous.clone()) {
+                    stack.push(previous.clone());
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Breadth first search where every step costs one. Stops after the layer where the first goal
+/// was found, so all goals at that distance are collected. Pass `|_| false` to explore everything.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Eq + Hash + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+    let mut best = None;
+
+    while let Some(state) = queue.pop_front() {
+        let distance = search.distances[&state];
+        if best.is_some_and(|best| distance > best) {
+            break;
+        }
+        if is_goal(&state) {
+            best.get_or_insert(distance);
+            search.goals.push(state.clone());
+        }
+        for next in successors(&state) {
+            if search.relax(&state, next.clone(), distance + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra over `(state, cost)` successors. Costs must not be negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Eq + Hash + Clone,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::ZERO, is_goal)
+}
+
+/// A* over `(state, cost)` successors. `heuristic` must never overestimate the remaining cost,
+/// otherwise the result is not guaranteed to be optimal.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Eq + Hash + Clone,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new(start.clone());
+    let mut frontier = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::ZERO,
+        state: start,
+    }]);
+    let mut best = None;
+
+    while let Some(Entry {
+        priority,
+        cost,
+        state,
+    }) = frontier.pop()
+    {
+        if best.is_some_and(|best| priority > best) {
+            break;
+        }
+        if cost > search.distances[&state] {
+            // Found a cheaper way here after this was queued
+            continue;
+        }
+        if is_goal(&state) {
+            best.get_or_insert(cost);
+            search.goals.push(state.clone());
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if search.relax(&state, next.clone(), next_cost) {
+                frontier.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    search
+}
+
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap is a max heap, cheapest entry has to compare as the largest
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+#[cfg(test)]
+use crate::{
+    grid::Grid,
+    vector::{Direction, Vector},
+};
+
+#[test]
+fn test_bfs_paths() {
+    let grid = Grid::parse("...\n.#.\n...", |char| char == '#');
+    let search = bfs(
+        Vector::new(0, 0),
+        |position| {
+            grid.neighbours(*position, false)
+                .filter(|x| !*x.1)
+                .map(|x| x.0)
+        },
+        |position| *position == Vector::new(2, 2),
+    );
+
+    assert_eq!(search.cost(), Some(4));
+    assert_eq!(search.path().unwrap().len(), 5);
+    assert_eq!(search.path_count(), 2);
+    assert_eq!(search.paths().len(), 2);
+    assert_eq!(search.on_optimal_paths().len(), 8);
+}
+
+#[test]
+fn test_long_path_count() {
+    // Deep enough to overflow the stack when counting recursively
+    let search = bfs(0, |x| [x + 1], |x| *x == 100_000);
+
+    assert_eq!(search.path_count(), 1);
+}
+
+#[test]
+fn test_dijkstra_with_turns() {
+    // Moving costs 1 and turning costs 1000
+    let grid = Grid::parse("#####\n#..E#\n#S#.#\n#####", |char| char == '#');
+    let start = (Vector::new(1, 2), Direction::Right);
+    let search = dijkstra(
+        start,
+        |(position, direction)| {
+            let mut next = vec![
+                ((*position, direction.turn_left()), 1000),
+                ((*position, direction.turn_right()), 1000),
+            ];
+            let forward = *position + *direction;
+            if grid.get(&forward) == Some(&false) {
+                next.push(((forward, *direction), 1));
+            }
+            next
+        },
+        |(position, _)| *position == Vector::new(3, 1),
+    );
+
+    assert_eq!(search.cost(), Some(2003));
+    assert_eq!(search.distances[&(Vector::new(1, 1), Direction::Up)], 1001);
+}
+
+#[test]
+fn test_astar_matches_dijkstra() {
+    let grid = Grid::parse(".....\n.###.\n...#.\n.#...", |char| char == '#');
+    let goal = Vector::new(4, 3);
+    let successors = |position: &Vector| {
+        grid.neighbours(*position, false)
+            .filter(|x| !*x.1)
+            .map(|x| (x.0, 1))
+            .collect::<Vec<_>>()
+    };
+    let heuristic = |position: &Vector| (goal.x - position.x).abs() + (goal.y - position.y).abs();
+
+    let astar = astar(Vector::new(0, 0), successors, heuristic, |x| *x == goal);
+    let dijkstra = dijkstra(Vector::new(0, 0), successors, |x| *x == goal);
+
+    assert_eq!(astar.cost(), Some(7));
+    assert_eq!(astar.cost(), dijkstra.cost());
+    assert_eq!(astar.path_count(), dijkstra.path_count());
+}
@@ -0,0 +1,243 @@
+use crate::{anim::Recorder, image::Image, render::Canvas};
+
+/// A puzzle that runs one step at a time, like a robot following its moves.
+pub trait Simulation {
+    /// Advances one step, false once there is nothing left to do.
+    fn step(&mut self) -> bool;
+
+    /// Goes back one step, false when already at the start.
+    fn undo(&mut self) -> bool;
+
+    /// Draws the current state. `overlays[i]` says whether overlay `i` of `overlays` is shown.
+    fn render(&self, overlays: &[bool]) -> Canvas;
+
+    /// Names of the layers that can be toggled, like "path".
+    fn overlays(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// The current state as an image for a recording, the rendered canvas without overlays
+    /// unless the simulation draws something better.
+    fn frame(&self) -> Image {
+        let overlays = vec![false; self.overlays().len()];
+        Image::from_canvas(&self.render(&overlays), 4)
+    }
+}
+
+/// Steps `simulation` until it ends or `recorder` is full, recording a frame before every
+/// step and one of the final state.
+pub fn record<S: Simulation>(simulation: &mut S, recorder: &mut Recorder) {
+    loop {
+        recorder.record(|| simulation.frame());
+        if recorder.is_full() || !simulation.step() {
+            return;
+        }
+    }
+}
+
+/// Keys understood by `Stepper::handle`. Digits typed before a key are its count.
+pub const HELP: &str =
+    "n: step, b: back, <count>n/b: that many, <count>g: jump to step, <count>o: toggle overlay, q: quit";
+
+/// Steps through a simulation and keeps track of where it is, without any terminal.
+pub struct Stepper<S> {
+    pub simulation: S,
+    pub step: usize,
+    pub overlays: Vec<bool>,
+    count: Option<usize>,
+}
+
+impl<S: Simulation> Stepper<S> {
+    pub fn new(simulation: S) -> Stepper<S> {
+        let overlays = vec![false; simulation.overlays().len()];
+        Stepper {
+            simulation,
+            step: 0,
+            overlays,
+            count: None,
+        }
+    }
+
+    /// Steps up to `count` times and returns how many steps were taken.
+    pub fn forward(&mut self, count: usize) -> usize {
+        let taken = (0..count).take_while(|_| self.simulation.step()).count();
+        self.step += taken;
+        taken
+    }
+
+    pub fn back(&mut self, count: usize) -> usize {
+        let taken = (0..count).take_while(|_| self.simulation.undo()).count();
+        self.step -= taken;
+        taken
+    }
+
+    /// Moves to `step`, or as close to it as the simulation goes.
+    pub fn jump(&mut self, step: usize) {
+        match step >= self.step {
+            true => self.forward(step - self.step),
+            false => self.back(self.step - step),
+        };
+    }
+
+    /// Shows or hides the overlay with 0-based `index`.
+    pub fn toggle(&mut self, index: usize) {
+        if let Some(shown) = self.overlays.get_mut(index) {
+            *shown = !*shown;
+        }
+    }
+
+    /// Acts on one key of `HELP`, false when the key asks to quit.
+    pub fn handle(&mut self, key: char) -> bool {
+        if let Some(digit) = key.to_digit(10) {
+            self.count = Some(self.count.unwrap_or(0).saturating_mul(10) + digit as usize);
+            return true;
+        }
+
+        let count = self.count.take();
+        match key {
+            'n' | ' ' => {
+                self.forward(count.unwrap_or(1));
+            }
+            'b' => {
+                self.back(count.unwrap_or(1));
+            }
+            'g' => self.jump(count.unwrap_or(0)),
+            'o' => self.toggle(count.unwrap_or(1).saturating_sub(1)),
+            'q' => return false,
+            _ => {}
+        }
+        true
+    }
+
+    pub fn status(&self) -> String {
+        let overlays: Vec<String> = self
+            .simulation
+            .overlays()
+            .iter()
+            .zip(&self.overlays)
+            .enumerate()
+            .map(|(index, (name, shown))| {
+                let mark = if *shown { 'x' } else { ' ' };
+                format!("{}[{}] {}", index + 1, mark, name)
+            })
+            .collect();
+        let count = match self.count {
+            Some(count) => format!(" | count {}", count),
+            None => String::new(),
+        };
+        format!("step {}{} | {}", self.step, count, overlays.join(" "))
+    }
+}
+
+/// Shows the simulation in the terminal and steps it with the keys of `HELP`. The arrow keys
+/// step forwards and backwards too.
+#[cfg(feature = "tui")]
+pub fn run<S: Simulation>(simulation: S) -> std::io::Result<()> {
+    use std::io::{self, Write};
+
+    use crossterm::{
+        cursor,
+        event::{self, Event, KeyCode, KeyEventKind},
+        execute, queue,
+        terminal::{self, ClearType},
+    };
+
+    let mut stepper = Stepper::new(simulation);
+    let mut out = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let result = (|| loop {
+        let canvas = stepper.simulation.render(&stepper.overlays).colours();
+        queue!(out, cursor::MoveTo(0, 0), terminal::Clear(ClearType::All))?;
+        // Raw mode does not return the cursor to the start of the line on its own
+        write!(out, "{}", canvas.to_string().replace('\n', "\r\n"))?;
+        write!(out, "{}\r\n{}\r\n", stepper.status(), HELP)?;
+        out.flush()?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        let char = match key.code {
+            KeyCode::Char(char) => char,
+            KeyCode::Right => 'n',
+            KeyCode::Left => 'b',
+            KeyCode::Esc => 'q',
+            _ => continue,
+        };
+        if !stepper.handle(char) {
+            return Ok(());
+        }
+    })();
+
+    execute!(out, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+#[cfg(test)]
+use crate::rect::Rect;
+
+#[test]
+fn test_stepper() {
+    // Counts up to 5
+    struct Counter(usize);
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            let moved = self.0 < 5;
+            self.0 += moved as usize;
+            moved
+        }
+
+        fn undo(&mut self) -> bool {
+            let moved = self.0 > 0;
+            self.0 = self.0.saturating_sub(1);
+            moved
+        }
+
+        fn render(&self, overlays: &[bool]) -> Canvas {
+            let background = if overlays[0] { '#' } else { '.' };
+            Canvas::new(Rect::new(0, 0, self.0 as isize, 1), background)
+        }
+
+        fn overlays(&self) -> &'static [&'static str] {
+            &["fill"]
+        }
+    }
+
+    let mut stepper = Stepper::new(Counter(0));
+    for key in "n3n".chars() {
+        assert!(stepper.handle(key));
+    }
+    assert_eq!(stepper.step, 4);
+
+    stepper.handle('9');
+    stepper.handle('n');
+    assert_eq!((stepper.step, stepper.simulation.0), (5, 5));
+
+    "2g1o".chars().for_each(|key| {
+        stepper.handle(key);
+    });
+    assert_eq!(stepper.step, 2);
+    assert_eq!(
+        stepper.simulation.render(&stepper.overlays).to_string(),
+        "##\n"
+    );
+    assert_eq!(stepper.status(), "step 2 | 1[x] fill");
+
+    stepper.handle('b');
+    stepper.handle('5');
+    assert_eq!(stepper.status(), "step 1 | count 5 | 1[x] fill");
+    assert!(!stepper.handle('q'));
+
+    let mut recorder = Recorder::new().every(2);
+    record(&mut stepper.simulation, &mut recorder);
+    assert_eq!(stepper.simulation.0, 5);
+    // Counts 1, 3 and 5, every second one from where the stepper left it
+    let widths: Vec<usize> = recorder.frames().iter().map(|x| x.width).collect();
+    assert_eq!(widths, vec![4, 12, 20]);
+}
@@ -0,0 +1,213 @@
+use std::{
+    fmt::{self, Display},
+    hint::black_box,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::ParseError,
+    input::{self, Kind},
+};
+
+/// Answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Answer {
+                fn from(value: $type) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(number: usize) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part 1"),
+            Part::Two => write!(f, "part 2"),
+        }
+    }
+}
+
+/// One day of the calendar. The input is parsed once and shared by both parts.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Answer;
+    fn part_two(input: &Self::Input) -> Answer;
+}
+
+/// Parses `input` and runs the requested parts, both if `part` is None.
+pub fn solve<S: Solution>(
+    input: &str,
+    part: Option<Part>,
+) -> Result<Vec<(Part, Answer)>, ParseError> {
+    let parsed = S::parse(input)?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+    Ok(parts
+        .into_iter()
+        .map(|part| match part {
+            Part::One => (part, S::part_one(&parsed)),
+            Part::Two => (part, S::part_two(&parsed)),
+        })
+        .collect())
+}
+
+/// `solve` for every day, so the days can be stored side by side.
+pub type Solver = fn(&str, Option<Part>) -> Result<Vec<(Part, Answer)>, ParseError>;
+
+/// How long each step of one `solve` took.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+/// Solves `input` once and times parsing and both parts on their own.
+pub fn time<S: Solution>(input: &str) -> Result<Timing, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(black_box(input))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part_one(&parsed));
+    let part_one = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part_two(&parsed));
+    let part_two = start.elapsed();
+
+    Ok(Timing {
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+/// `time` for every day, like `Solver`.
+pub type Timer = fn(&str) -> Result<Timing, ParseError>;
+
+/// Entry point of the day binaries. Solves the real input of `day` and prints both parts.
+pub fn run<S: Solution>(day: usize) -> ExitCode {
+    let input = match input::read(day, Kind::Real) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    match solve::<S>(&input, None) {
+        Ok(answers) => {
+            for (part, answer) in answers {
+                println!("{}: {}", part, answer);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("invalid input for day {}, {}", day, error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::error::number;
+
+#[test]
+fn test_solve() {
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input = Vec<usize>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(index, line)| number(index, line, line))
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Answer {
+            input.iter().sum::<usize>().into()
+        }
+
+        fn part_two(_: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+    }
+
+    assert_eq!(
+        solve::<Lines>("1\n2\n3", None),
+        Ok(vec![
+            (Part::One, Answer::Number(6)),
+            (Part::Two, Answer::Unsolved)
+        ])
+    );
+    assert_eq!(
+        solve::<Lines>("4", Some(Part::One)),
+        Ok(vec![(Part::One, Answer::Number(4))])
+    );
+    assert_eq!(
+        solve::<Lines>("4\nfive", None),
+        Err(ParseError::new(2, 1, "five", "a number"))
+    );
+    assert_eq!(Answer::from(-3isize).to_string(), "-3");
+}
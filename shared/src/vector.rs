@@ -1,32 +1,70 @@
-use std::ops::{Add, AddAssign, Mul, Sub};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub};
 
-impl Vector {
-    pub const fn new(x: isize, y: isize) -> Vector {
+use crate::num::{Integer, Signed};
+
+impl<T> Vector<T> {
+    pub const fn new(x: T, y: T) -> Vector<T> {
         Vector { x, y }
     }
+}
 
-    pub fn delta(&self, other: &Vector) -> Vector {
+impl<T: Integer> Vector<T> {
+    pub fn delta(&self, other: &Vector<T>) -> Vector<T> {
         other - self
     }
-    pub fn mirror(&self) -> Vector {
-        Vector {
-            x: -self.x,
-            y: -self.y,
-        }
+
+    pub fn checked_add(&self, other: &Vector<T>) -> Option<Vector<T>> {
+        Some(Vector::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    pub fn checked_sub(&self, other: &Vector<T>) -> Option<Vector<T>> {
+        Some(Vector::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+        ))
+    }
+
+    pub fn checked_mul(&self, scalar: T) -> Option<Vector<T>> {
+        Some(Vector::new(
+            self.x.checked_mul(scalar)?,
+            self.y.checked_mul(scalar)?,
+        ))
+    }
+
+    /// Widens the components, e.g. `Vector<i32>` to `Vector<i128>`.
+    pub fn convert<U: From<T>>(self) -> Vector<U> {
+        Vector::new(U::from(self.x), U::from(self.y))
+    }
+
+    /// Narrows the components, `None` if either of them does not fit.
+    pub fn try_convert<U: TryFrom<T>>(self) -> Option<Vector<U>> {
+        Some(Vector::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl<T: Signed> Vector<T> {
+    pub fn mirror(&self) -> Vector<T> {
+        -*self
     }
 }
 
-impl AddAssign<Vector> for Vector {
-    fn add_assign(&mut self, rhs: Vector) {
+impl<T: Integer> AddAssign<Vector<T>> for Vector<T> {
+    fn add_assign(&mut self, rhs: Vector<T>) {
         self.x += rhs.x;
         self.y += rhs.y;
     }
 }
 
-impl Add<Vector> for Vector {
+impl<T: Integer> Add<Vector<T>> for Vector<T> {
     type Output = Self;
 
-    fn add(self, rhs: Vector) -> Self::Output {
+    fn add(self, rhs: Vector<T>) -> Self::Output {
         Self {
             x: self.x + rhs.x,
             y: self.y + rhs.y,
@@ -34,10 +72,10 @@ impl Add<Vector> for Vector {
     }
 }
 
-impl<'a, 'b> Add<&'b Vector> for &'a Vector {
-    type Output = Vector;
+impl<T: Integer> Add<&Vector<T>> for &Vector<T> {
+    type Output = Vector<T>;
 
-    fn add(self, rhs: &'b Vector) -> Self::Output {
+    fn add(self, rhs: &Vector<T>) -> Self::Output {
         Self::Output {
             x: self.x + rhs.x,
             y: self.y + rhs.y,
@@ -45,10 +83,18 @@ impl<'a, 'b> Add<&'b Vector> for &'a Vector {
     }
 }
 
-impl Sub<&Vector> for &Vector {
-    type Output = Vector;
+impl<T: Integer> Sub<Vector<T>> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: Vector<T>) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<T: Integer> Sub<&Vector<T>> for &Vector<T> {
+    type Output = Vector<T>;
 
-    fn sub(self, rhs: &Vector) -> Self::Output {
+    fn sub(self, rhs: &Vector<T>) -> Self::Output {
         Vector {
             x: self.x - rhs.x,
             y: self.y - rhs.y,
@@ -56,10 +102,10 @@ impl Sub<&Vector> for &Vector {
     }
 }
 
-impl Mul<isize> for Vector {
-    type Output = Vector;
+impl<T: Integer> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn mul(self, rhs: isize) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Vector {
             x: self.x * rhs,
             y: self.y * rhs,
@@ -67,14 +113,228 @@ impl Mul<isize> for Vector {
     }
 }
 
-impl From<(isize, isize)> for Vector {
-    fn from(value: (isize, isize)) -> Self {
+impl<T: Integer> Div<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Vector {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+impl<T: Integer> Rem<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn rem(self, rhs: T) -> Self::Output {
+        Vector {
+            x: self.x % rhs,
+            y: self.y % rhs,
+        }
+    }
+}
+
+impl<T: Signed> Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Self::Output {
+        Vector {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Add<Direction> for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + rhs.vector()
+    }
+}
+
+impl AddAssign<Direction> for Vector {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self += rhs.vector();
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(value: Direction) -> Self {
+        value.vector()
+    }
+}
+
+impl<T> From<(T, T)> for Vector<T> {
+    fn from(value: (T, T)) -> Self {
         Vector::new(value.0, value.1)
     }
 }
 
 #[derive(Default, PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone, Hash)]
-pub struct Vector {
-    pub x: isize,
-    pub y: isize,
+pub struct Vector<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Compass directions in text coordinates, where `y` grows downwards so `Up` is `(0, -1)`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    pub const CARDINALS: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Cardinal directions first, then the diagonals.
+    pub const ALL8: [Direction; 8] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+        Direction::UpRight,
+        Direction::DownRight,
+        Direction::DownLeft,
+        Direction::UpLeft,
+    ];
+
+    // Clockwise order, so turning is just moving along this array
+    const CLOCKWISE: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    pub const fn vector(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(0, -1),
+            Direction::UpRight => Vector::new(1, -1),
+            Direction::Right => Vector::new(1, 0),
+            Direction::DownRight => Vector::new(1, 1),
+            Direction::Down => Vector::new(0, 1),
+            Direction::DownLeft => Vector::new(-1, 1),
+            Direction::Left => Vector::new(-1, 0),
+            Direction::UpLeft => Vector::new(-1, -1),
+        }
+    }
+
+    fn rotate(self, eighths: usize) -> Direction {
+        Direction::CLOCKWISE[(self as usize + eighths) % 8]
+    }
+
+    /// Rotates 90 degrees clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Rotates 90 degrees counter-clockwise.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !self.is_horizontal() && !self.is_vertical()
+    }
+
+    /// Parses arrows (`^>v<`), `UDLR` and compass letters `NESW`.
+    pub fn from_char(char: char) -> Option<Direction> {
+        match char {
+            '^' | 'U' | 'N' => Some(Direction::Up),
+            '>' | 'R' | 'E' => Some(Direction::Right),
+            'v' | 'D' | 'S' => Some(Direction::Down),
+            '<' | 'L' | 'W' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test_turn_right() {
+    let mut direction = Direction::Right;
+    direction = direction.turn_right();
+    assert_eq!(direction, Direction::Down);
+
+    direction = direction.turn_right();
+    assert_eq!(direction, Direction::Left);
+
+    direction = direction.turn_right();
+    assert_eq!(direction, Direction::Up);
+
+    direction = direction.turn_right();
+    assert_eq!(direction, Direction::Right);
+
+    assert_eq!(Direction::UpRight.turn_right(), Direction::DownRight);
+}
+
+#[test]
+fn test_turn_left_and_reverse() {
+    for direction in Direction::ALL8 {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.reverse().vector(), direction.vector().mirror());
+    }
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+}
+
+#[test]
+fn test_from_char() {
+    assert_eq!(Direction::from_char('^'), Some(Direction::Up));
+    assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+    assert_eq!(Direction::from_char('W'), Some(Direction::Left));
+    assert_eq!(Direction::from_char('R'), Some(Direction::Right));
+    assert_eq!(Direction::from_char('x'), None);
+}
+
+#[test]
+fn test_generic_vector() {
+    let big: Vector<i128> = Vector::new(10_000_000_000_000, -3);
+    assert_eq!(big * 2, Vector::new(20_000_000_000_000, -6));
+    assert_eq!(-big, Vector::new(-10_000_000_000_000, 3));
+    assert_eq!(Vector::new(7u16, 9) / 2, Vector::new(3, 4));
+    assert_eq!(Vector::new(7i32, 9) % 4, Vector::new(3, 1));
+
+    let small: Vector<i32> = Vector::new(1, -2);
+    assert_eq!(small.convert::<i128>(), Vector::new(1i128, -2));
+    assert_eq!(big.try_convert::<i32>(), None);
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    let vector: Vector<u8> = Vector::new(200, 1);
+    assert_eq!(
+        vector.checked_add(&Vector::new(55, 1)),
+        Some(Vector::new(255, 2))
+    );
+    assert_eq!(vector.checked_add(&Vector::new(56, 0)), None);
+    assert_eq!(vector.checked_sub(&Vector::new(0, 2)), None);
+    assert_eq!(vector.checked_mul(2), None);
 }